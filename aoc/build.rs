@@ -0,0 +1,26 @@
+//! Generates a regression test for every day with recorded answers, see `tests/regression.rs`.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let answers = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../answers.tsv");
+    println!("cargo:rerun-if-changed={}", answers.display());
+
+    let text = fs::read_to_string(&answers).unwrap_or_default();
+    let days = text
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split('\t').next()?.parse::<u8>().ok())
+        .collect::<BTreeSet<_>>();
+
+    let tests = days
+        .iter()
+        .map(|day| format!("#[test]\nfn day_{:02}() {{\n    check({});\n}}\n", day, day))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("regression.rs");
+    fs::write(out, tests).unwrap();
+}
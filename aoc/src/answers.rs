@@ -0,0 +1,265 @@
+//! The answers file, which records the expected answer to each part for every known input.
+//!
+//! Each line holds the day, the part, the `input_id` of the input and the answer, separated by
+//! tabs. Line breaks, tabs and backslashes in answers are escaped as `\n`, `\t` and `\\`, and
+//! lines starting with `#` are comments.
+
+use crate::days::Day;
+use lib::input::input_id;
+use lib::solution::{Part, Unsolved};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The answers file, relative to the workspace root.
+pub const ANSWERS_FILE: &str = "answers.tsv";
+
+/// The directory with the inputs the answers file has answers for, relative to the workspace
+/// root.
+pub const FIXTURE_DIR: &str = "fixtures";
+
+/// Resolves `path` against the workspace root, so that the answers and fixtures are found from
+/// any working directory.
+pub fn workspace_path(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc is a member of the workspace")
+        .join(path)
+}
+
+/// The expected answer to one part of a day for one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+impl Answer {
+    fn parse(line: &str) -> Result<Self, String> {
+        let fields = line.split('\t').collect::<Vec<_>>();
+        if fields.len() != 4 {
+            return Err(format!("Expected 4 fields, found {}", fields.len()));
+        }
+        let day = fields[0]
+            .parse()
+            .map_err(|_| format!("'{}' is not a day", fields[0]))?;
+        Ok(Answer {
+            day,
+            part: fields[1].parse()?,
+            input: fields[2].to_string(),
+            answer: unescape(fields[3])?,
+        })
+    }
+}
+
+/// Formats the answer as a line of the answers file.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.day,
+            part,
+            self.input,
+            escape(&self.answer)
+        )
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+fn unescape(answer: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some(other) => return Err(format!("Unknown escape '\\{}'", other)),
+            None => return Err(String::from("Unfinished escape")),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let answers = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(index, line)| {
+                Answer::parse(line).map_err(|error| format!("Line {}: {}", index + 1, error))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Answers { answers })
+    }
+
+    /// Reads the answers file at `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Answers::parse(&text).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) => Err(format!("Could not read {}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|answer| answer.day == day && answer.part == part && answer.input == input)
+            .map(|answer| answer.answer.as_str())
+    }
+
+    /// The parts of `day` with a recorded answer for `input`.
+    pub fn parts(&self, day: u8, input: &str) -> Vec<Part> {
+        Part::BOTH
+            .iter()
+            .copied()
+            .filter(|&part| self.get(day, part, input).is_some())
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.answers.iter()
+    }
+}
+
+/// How an answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    Unknown,
+    /// The part is not solved yet, so there is nothing to compare or record.
+    Unsolved,
+}
+
+/// The answer to one part together with its check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub part: Part,
+    pub answer: String,
+    pub check: Check,
+}
+
+/// Solves `parts` of `day` for `input` and compares the answers to the recorded ones, failing
+/// when `input` cannot be parsed.
+pub fn verify(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    answers: &Answers,
+) -> Result<Vec<Outcome>, String> {
+    let id = input_id(input);
+    let solved = day.puzzle.solve(input, parts)?;
+    let outcomes = parts
+        .iter()
+        .zip(solved)
+        .map(|(&part, answer)| {
+            let check = match answers.get(day.day, part, &id) {
+                _ if answer == Unsolved.to_string() => Check::Unsolved,
+                Some(expected) if expected == answer => Check::Correct,
+                Some(expected) => Check::Wrong {
+                    expected: expected.to_string(),
+                },
+                None => Check::Unknown,
+            };
+            Outcome {
+                part,
+                answer,
+                check,
+            }
+        })
+        .collect();
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{verify, workspace_path, Answer, Answers, Check, ANSWERS_FILE};
+    use crate::days;
+    use lib::input::input_id;
+    use lib::solution::Part;
+
+    #[test]
+    fn test_load_answers() {
+        assert!(Answers::load(&workspace_path(ANSWERS_FILE)).is_ok());
+        assert!(Answers::load(&workspace_path("missing/answers.tsv")).is_err());
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let text = "# day\tpart\tinput\tanswer\n14\t1\tab12\t892207\n8\t2\tab12\t#.\\n.#\\\\\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.get(14, Part::One, "ab12"), Some("892207"));
+        assert_eq!(answers.get(14, Part::Two, "ab12"), None);
+        assert_eq!(answers.get(8, Part::Two, "ab12"), Some("#.\n.#\\"));
+        assert_eq!(answers.parts(14, "ab12"), vec![Part::One]);
+
+        let lines = answers.iter().map(Answer::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec!["14\t1\tab12\t892207", "8\t2\tab12\t#.\\n.#\\\\"]
+        );
+
+        assert_eq!(
+            Answers::parse("\n14\t3\tab12\t1"),
+            Err(String::from("Line 2: Invalid part '3'"))
+        );
+        assert_eq!(
+            Answers::parse("14\t1\tab12"),
+            Err(String::from("Line 1: Expected 4 fields, found 3"))
+        );
+        assert!(Answers::parse("14\t1\tab12\t\\x").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let day = days::find(1).unwrap();
+        let input = "12\n14\n";
+        let id = input_id(input);
+        let answers = Answers::parse(&format!("1\t1\t{}\t4\n1\t2\t{}\t5\n", id, id)).unwrap();
+        let outcomes = verify(day, input, &Part::BOTH, &answers).unwrap();
+        assert_eq!(outcomes[0].check, Check::Correct);
+        assert_eq!(
+            outcomes[1].check,
+            Check::Wrong {
+                expected: String::from("5")
+            }
+        );
+        assert_eq!(outcomes[1].answer, "4");
+
+        let outcomes = verify(day, "1969", &[Part::One], &answers).unwrap();
+        assert_eq!(outcomes[0].check, Check::Unknown);
+
+        let outcomes = verify(days::find(18).unwrap(), "#@a#\n", &[Part::Two], &answers).unwrap();
+        assert_eq!(outcomes[0].check, Check::Unsolved);
+
+        assert!(verify(day, "12\nx\n", &Part::BOTH, &answers).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use lib::solution::Puzzle;
+
+/// A solved day.
+pub struct Day {
+    pub day: u8,
+    pub puzzle: &'static dyn Puzzle,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        puzzle: &day_01::Day01,
+    },
+    Day {
+        day: 2,
+        puzzle: &day_02::Day02,
+    },
+    Day {
+        day: 3,
+        puzzle: &day_03::Day03,
+    },
+    Day {
+        day: 4,
+        puzzle: &day_04::Day04,
+    },
+    Day {
+        day: 5,
+        puzzle: &day_05::Day05,
+    },
+    Day {
+        day: 6,
+        puzzle: &day_06::Day06,
+    },
+    Day {
+        day: 7,
+        puzzle: &day_07::Day07,
+    },
+    Day {
+        day: 8,
+        puzzle: &day_08::Day08,
+    },
+    Day {
+        day: 9,
+        puzzle: &day_09::Day09,
+    },
+    Day {
+        day: 10,
+        puzzle: &day_10::Day10,
+    },
+    Day {
+        day: 11,
+        puzzle: &day_11::Day11,
+    },
+    Day {
+        day: 12,
+        puzzle: &day_12::Day12,
+    },
+    Day {
+        day: 13,
+        puzzle: &day_13::Day13,
+    },
+    Day {
+        day: 14,
+        puzzle: &day_14::Day14,
+    },
+    Day {
+        day: 15,
+        puzzle: &day_15::Day15,
+    },
+    Day {
+        day: 16,
+        puzzle: &day_16::Day16,
+    },
+    Day {
+        day: 17,
+        puzzle: &day_17::Day17,
+    },
+    Day {
+        day: 18,
+        puzzle: &day_18::Day18,
+    },
+    Day {
+        day: 19,
+        puzzle: &day_19::Day19,
+    },
+    Day {
+        day: 20,
+        puzzle: &day_20::Day20,
+    },
+    Day {
+        day: 21,
+        puzzle: &day_21::Day21,
+    },
+    Day {
+        day: 24,
+        puzzle: &day_24::Day24,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
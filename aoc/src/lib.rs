@@ -0,0 +1,6 @@
+//! The registry of solved days and their recorded answers, shared by the runner and the
+//! regression tests.
+
+pub mod answers;
+pub mod days;
+pub mod timings;
@@ -0,0 +1,433 @@
+use aoc::answers::{self, Answer, Answers, Check, ANSWERS_FILE, FIXTURE_DIR};
+use aoc::days::{self, Day, DAYS};
+use aoc::timings::{self, DayTimings, Format};
+use lib::input::{input_id, InputSource};
+use lib::log::{self, Filter, Level};
+use lib::solution::Part;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--fixtures]
+                [--time] [--time-export <file.json|file.csv>]
+       aoc verify <day|all> [--part <1|2>] [--input <path|->] [--fixtures] [--record]
+
+Inputs are read from inputs/dayNN.txt unless a path is given, where - reads stdin, or
+--fixtures reads fixtures/dayNN.txt of the workspace instead. verify compares the answers
+to answers.tsv of the workspace, and --record adds the answers which are not recorded
+yet. --time prints how long each day took and --time-export also writes it to a file.
+
+Answers go to stdout and diagnostics to stderr. Those are filtered like warn,day_07=trace
+with --log or the AOC_LOG variable, -v shows more of them and -q only shows errors.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Run,
+    Verify { record: bool },
+}
+
+/// What to solve, as given on the command line.
+struct Command {
+    action: Action,
+    days: Vec<&'static Day>,
+    parts: Vec<Part>,
+    /// Replaces the default input, only for a single day.
+    input: Option<InputSource>,
+    fixtures: bool,
+    /// Whether to print the timings of each day after the answers.
+    time: bool,
+    time_export: Option<PathBuf>,
+    /// Replaces the filter from `AOC_LOG`.
+    log: Option<Filter>,
+    /// How many levels more verbose the diagnostics are, where -q counts as -1.
+    verbosity: i8,
+}
+
+impl Command {
+    fn source(&self, day: u8) -> InputSource {
+        match &self.input {
+            Some(source) => source.clone(),
+            None if self.fixtures => {
+                let fixtures = answers::workspace_path(FIXTURE_DIR);
+                InputSource::File(fixtures.join(format!("day{:02}.txt", day)))
+            }
+            None => InputSource::default_for(day),
+        }
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    match log_filter(&command, env::var(log::ENV_VAR).ok()) {
+        Ok(filter) => log::set_filter(filter),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
+    }
+    let succeeded = match command.action {
+        Action::Run => run(&command),
+        Action::Verify { record } => verify(&command, record),
+    };
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// Combines the filter from `--log` or `AOC_LOG` with the verbosity flags.
+fn log_filter(command: &Command, env: Option<String>) -> Result<Filter, String> {
+    let filter = match (&command.log, env) {
+        (Some(filter), _) => filter.clone(),
+        (None, Some(spec)) => spec
+            .parse()
+            .map_err(|error| format!("{}: {}", log::ENV_VAR, error))?,
+        (None, None) => Filter::default(),
+    };
+    Ok(match command.verbosity {
+        verbosity if verbosity < 0 => filter.default_level(Some(Level::Error)),
+        verbosity => filter.more_verbose(verbosity as usize),
+    })
+}
+
+/// Reads the input of every day of `command`, reporting the days without one.
+fn inputs<'a>(command: &'a Command) -> impl Iterator<Item = Option<(&'static Day, String)>> + 'a {
+    command
+        .days
+        .iter()
+        .map(move |&day| match command.source(day.day).read() {
+            Ok(input) => Some((day, input)),
+            Err(error) => {
+                eprintln!("Day {:02}: {}", day.day, error);
+                None
+            }
+        })
+}
+
+fn run(command: &Command) -> bool {
+    let mut succeeded = true;
+    let mut days = Vec::new();
+    for input in inputs(command) {
+        let (day, input) = match input {
+            Some(input) => input,
+            None => {
+                succeeded = false;
+                continue;
+            }
+        };
+        let (answers, timings) = match day.puzzle.solve_timed(&input, &command.parts) {
+            Ok(solved) => solved,
+            Err(error) => {
+                eprintln!("Day {:02}: {}", day.day, error);
+                succeeded = false;
+                continue;
+            }
+        };
+        for (part, answer) in command.parts.iter().zip(answers) {
+            println!("Day {:02}, {}: {}", day.day, part, answer);
+        }
+        days.push(DayTimings {
+            day: day.day,
+            timings,
+        });
+    }
+
+    if command.time {
+        print!("\n{}", timings::table(&days));
+    }
+    if let Some(path) = &command.time_export {
+        let format = Format::from_path(path).expect("Checked when parsing the arguments");
+        if let Err(error) = fs::write(path, format.export(&days)) {
+            eprintln!("Could not write {}: {}", path.display(), error);
+            succeeded = false;
+        }
+    }
+    succeeded
+}
+
+fn verify(command: &Command, record: bool) -> bool {
+    let path = answers::workspace_path(ANSWERS_FILE);
+    // Only recording may start a new answers file
+    let loaded = if record && !path.exists() {
+        Ok(Answers::default())
+    } else {
+        Answers::load(&path)
+    };
+    let answers = match loaded {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    let (mut correct, mut wrong, mut unknown, mut unsolved, mut failed) = (0, 0, 0, 0, 0);
+    let mut recorded = Vec::new();
+    for input in inputs(command) {
+        let (day, input) = match input {
+            Some(input) => input,
+            None => {
+                failed += 1;
+                continue;
+            }
+        };
+        let outcomes = match answers::verify(day, &input, &command.parts, &answers) {
+            Ok(outcomes) => outcomes,
+            Err(error) => {
+                eprintln!("Day {:02}: {}", day.day, error);
+                failed += 1;
+                continue;
+            }
+        };
+        for outcome in outcomes {
+            let label = format!("Day {:02}, {}", day.day, outcome.part);
+            match outcome.check {
+                Check::Correct => {
+                    correct += 1;
+                    println!("{}: ok", label);
+                }
+                Check::Wrong { expected } => {
+                    wrong += 1;
+                    println!("{}: expected {}, got {}", label, expected, outcome.answer);
+                }
+                Check::Unknown => {
+                    unknown += 1;
+                    println!("{}: {} is not recorded", label, outcome.answer);
+                    recorded.push(Answer {
+                        day: day.day,
+                        part: outcome.part,
+                        input: input_id(&input),
+                        answer: outcome.answer,
+                    });
+                }
+                Check::Unsolved => {
+                    unsolved += 1;
+                    println!("{}: unsolved", label);
+                }
+            }
+        }
+    }
+    println!(
+        "{} correct, {} wrong, {} unsolved, {} not recorded, {} without valid input",
+        correct, wrong, unsolved, unknown, failed
+    );
+
+    if record && !recorded.is_empty() {
+        if let Err(error) = append(&path, &recorded) {
+            eprintln!("Could not write {}: {}", path.display(), error);
+            return false;
+        }
+        println!("Recorded {} answers in {}", recorded.len(), path.display());
+    }
+    wrong == 0 && failed == 0
+}
+
+fn append(path: &Path, recorded: &[Answer]) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for answer in recorded {
+        writeln!(file, "{}", answer)?;
+    }
+    Ok(())
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+    let mut action = match args.next() {
+        Some("run") => Action::Run,
+        Some("verify") => Action::Verify { record: false },
+        Some(other) => return Err(format!("Unknown command '{}'", other)),
+        None => return Err(String::from("Missing command")),
+    };
+
+    let days = match args.next() {
+        Some("all") => DAYS.iter().collect(),
+        Some(day) => {
+            let day = day.parse().map_err(|_| format!("'{}' is not a day", day))?;
+            let entry = days::find(day).ok_or(format!("Day {} is not solved yet", day))?;
+            vec![entry]
+        }
+        None => return Err(String::from("Missing day")),
+    };
+
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = None;
+    let mut fixtures = false;
+    let mut time = false;
+    let mut time_export = None;
+    let mut log = None;
+    let mut verbosity = 0;
+    while let Some(option) = args.next() {
+        match option {
+            "--part" => {
+                let part = args.next().unwrap_or_default();
+                parts = vec![part.parse()?];
+            }
+            "--input" => {
+                let path = args.next().ok_or("Missing input path")?;
+                input = Some(InputSource::from_arg(path));
+            }
+            "--fixtures" => fixtures = true,
+            "--time" => time = true,
+            "--time-export" => {
+                let path = PathBuf::from(args.next().ok_or("Missing export path")?);
+                Format::from_path(&path)?;
+                time = true;
+                time_export = Some(path);
+            }
+            "--log" => log = Some(args.next().ok_or("Missing log filter")?.parse()?),
+            "-q" => verbosity = -1,
+            flags if flags.starts_with("-v") && flags[1..].chars().all(|c| c == 'v') => {
+                verbosity = (flags.len() - 1).min(4) as i8;
+            }
+            "--record" => match action {
+                Action::Verify { .. } => action = Action::Verify { record: true },
+                Action::Run => return Err(String::from("Only verify can record answers")),
+            },
+            other if other.starts_with("--") => return Err(format!("Unknown option '{}'", other)),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+    if input.is_some() && days.len() > 1 {
+        return Err(String::from("An input path needs a single day"));
+    }
+    if time && action != Action::Run {
+        return Err(String::from("Only run can time days"));
+    }
+    if input.is_some() && fixtures {
+        return Err(String::from(
+            "An input path cannot be combined with --fixtures",
+        ));
+    }
+
+    Ok(Command {
+        action,
+        days,
+        parts,
+        input,
+        fixtures,
+        time,
+        time_export,
+        log,
+        verbosity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{log_filter, parse_args, Action};
+    use aoc::days::DAYS;
+    use lib::input::InputSource;
+    use lib::log::{Filter, Level};
+    use lib::solution::Part;
+    use std::path::PathBuf;
+
+    fn parse(args: &str) -> Result<(Vec<u8>, Vec<Part>), String> {
+        let args = args
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        parse_args(&args).map(|command| {
+            let days = command.days.iter().map(|day| day.day).collect();
+            (days, command.parts)
+        })
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse("run 14"), Ok((vec![14], vec![Part::One, Part::Two])));
+        assert_eq!(parse("run 7 --part 2"), Ok((vec![7], vec![Part::Two])));
+        let (days, _) = parse("run all --part 1").unwrap();
+        assert_eq!(days.len(), DAYS.len());
+
+        assert_eq!(
+            parse("run 22"),
+            Err(String::from("Day 22 is not solved yet"))
+        );
+        assert_eq!(parse("run x"), Err(String::from("'x' is not a day")));
+        assert_eq!(
+            parse("run 1 --part 3"),
+            Err(String::from("Invalid part '3'"))
+        );
+        assert_eq!(
+            parse("check 1"),
+            Err(String::from("Unknown command 'check'"))
+        );
+        assert_eq!(
+            parse("run 1 --record"),
+            Err(String::from("Only verify can record answers"))
+        );
+        assert!(parse("run").is_err());
+        assert_eq!(
+            parse("verify 1 --time"),
+            Err(String::from("Only run can time days"))
+        );
+        assert!(parse("run 1 --time-export timings.txt").is_err());
+        assert_eq!(
+            parse("run all --input day14.txt"),
+            Err(String::from("An input path needs a single day"))
+        );
+        assert!(parse("run 1 --part 1 2").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let args = ["run", "14", "--input", "-", "--part", "1"];
+        let command = parse_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>());
+        let command = command.unwrap();
+        assert_eq!(command.input, Some(InputSource::Stdin));
+        assert_eq!(command.parts, vec![Part::One]);
+        assert_eq!(command.action, Action::Run);
+
+        let args = ["verify", "all", "--fixtures", "--record"];
+        let command = parse_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>());
+        let command = command.unwrap();
+        assert_eq!(command.action, Action::Verify { record: true });
+        assert!(command.fixtures);
+
+        let args = ["run", "all", "--time-export", "timings.csv"];
+        let command = parse_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>());
+        let command = command.unwrap();
+        assert!(command.time);
+        assert_eq!(command.time_export, Some(PathBuf::from("timings.csv")));
+    }
+
+    #[test]
+    fn test_log_filter() {
+        let filter = |args: &str, env: Option<&str>| {
+            let args = args
+                .split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            log_filter(&parse_args(&args).unwrap(), env.map(String::from))
+        };
+        assert_eq!(filter("run 7", None), Ok(Filter::default()));
+        assert_eq!(
+            filter("run 7 -vv", Some("day_07=trace")),
+            Ok(Filter::new(Some(Level::Debug)).module("day_07", Some(Level::Trace)))
+        );
+        assert_eq!(
+            filter("run 7 -q --log info,intcode=off", Some("trace")),
+            Ok(Filter::new(Some(Level::Error)).module("intcode", None))
+        );
+        assert!(filter("run 7", Some("day_07=loud")).is_err());
+
+        let args = ["run", "7", "--log", "day_07=loud"];
+        assert!(parse_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>()).is_err());
+    }
+
+    #[test]
+    fn test_days_are_registered_once() {
+        let mut days = DAYS.iter().map(|day| day.day).collect::<Vec<_>>();
+        days.dedup();
+        assert_eq!(days.len(), DAYS.len());
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
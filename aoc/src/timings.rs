@@ -0,0 +1,209 @@
+//! Reports how long each day took, as a table or exported as JSON or CSV for tracking trends.
+//!
+//! Exported durations are in microseconds, with parts that were not solved left empty.
+
+use lib::solution::{Part, Timings};
+use std::path::Path;
+use std::time::Duration;
+
+/// The timings of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTimings {
+    pub day: u8,
+    pub timings: Timings,
+}
+
+/// The formats timings can be exported as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Picks the format from the extension of `path`.
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Ok(Format::Json),
+            Some("csv") => Ok(Format::Csv),
+            _ => Err(format!(
+                "Cannot export timings to '{}', use .json or .csv",
+                path.display()
+            )),
+        }
+    }
+
+    pub fn export(self, days: &[DayTimings]) -> String {
+        match self {
+            Format::Json => json(days),
+            Format::Csv => csv(days),
+        }
+    }
+}
+
+/// Formats `duration` with a unit which keeps it short, like `850µs` or `1.24s`.
+fn human(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1e3)
+    } else {
+        format!("{:.2}s", micros as f64 / 1e6)
+    }
+}
+
+pub fn table(days: &[DayTimings]) -> String {
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Parse"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+        String::from("Total"),
+    ]];
+    for day in days {
+        let part = |part| day.timings.part(part).map_or(String::from("-"), human);
+        rows.push([
+            format!("{:02}", day.day),
+            human(day.timings.parse),
+            part(Part::One),
+            part(Part::Two),
+            human(day.timings.total()),
+        ]);
+    }
+    let total = days.iter().map(|day| day.timings.total()).sum();
+    rows.push([
+        String::from("All"),
+        String::new(),
+        String::new(),
+        String::new(),
+        human(total),
+    ]);
+
+    let mut widths = [0; 5];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:>1$}", cell, width))
+                .collect::<Vec<_>>();
+            format!("{}\n", cells.join("  ").trim_end())
+        })
+        .collect()
+}
+
+fn micros(duration: Option<Duration>) -> Option<u128> {
+    duration.map(|duration| duration.as_micros())
+}
+
+fn fields(day: &DayTimings) -> [(&'static str, Option<u128>); 5] {
+    [
+        ("day", Some(u128::from(day.day))),
+        ("parse_us", micros(Some(day.timings.parse))),
+        ("part1_us", micros(day.timings.part(Part::One))),
+        ("part2_us", micros(day.timings.part(Part::Two))),
+        ("total_us", micros(Some(day.timings.total()))),
+    ]
+}
+
+pub fn csv(days: &[DayTimings]) -> String {
+    let mut csv = String::from("day,parse_us,part1_us,part2_us,total_us\n");
+    for day in days {
+        let values = fields(day)
+            .iter()
+            .map(|(_, value)| value.map_or(String::new(), |value| value.to_string()))
+            .collect::<Vec<_>>();
+        csv.push_str(&values.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+pub fn json(days: &[DayTimings]) -> String {
+    let objects = days
+        .iter()
+        .map(|day| {
+            let members = fields(day)
+                .iter()
+                .map(|(name, value)| match value {
+                    Some(value) => format!("\"{}\": {}", name, value),
+                    None => format!("\"{}\": null", name),
+                })
+                .collect::<Vec<_>>();
+            format!("  {{{}}}", members.join(", "))
+        })
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timings::{human, table, DayTimings, Format};
+    use lib::solution::{Part, Timings};
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn days() -> Vec<DayTimings> {
+        vec![
+            DayTimings {
+                day: 1,
+                timings: Timings {
+                    parse: Duration::from_micros(12),
+                    parts: vec![
+                        (Part::One, Duration::from_micros(30)),
+                        (Part::Two, Duration::from_millis(2)),
+                    ],
+                },
+            },
+            DayTimings {
+                day: 19,
+                timings: Timings {
+                    parse: Duration::from_micros(250),
+                    parts: vec![(Part::Two, Duration::from_secs(16))],
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!(human(Duration::from_micros(850)), "850µs");
+        assert_eq!(human(Duration::from_micros(12_345)), "12.35ms");
+        assert_eq!(human(Duration::from_millis(1_240)), "1.24s");
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            table(&days()),
+            "Day  Parse  Part 1  Part 2   Total\n\
+             \x2001   12µs    30µs  2.00ms  2.04ms\n\
+             \x2019  250µs       -  16.00s  16.00s\n\
+             All                         16.00s\n"
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        assert_eq!(
+            Format::Csv.export(&days()),
+            "day,parse_us,part1_us,part2_us,total_us\n1,12,30,2000,2042\n19,250,,16000000,16000250\n"
+        );
+        assert_eq!(
+            Format::Json.export(&days()),
+            "[\n  {\"day\": 1, \"parse_us\": 12, \"part1_us\": 30, \"part2_us\": 2000, \"total_us\": 2042},\n  \
+             {\"day\": 19, \"parse_us\": 250, \"part1_us\": null, \"part2_us\": 16000000, \"total_us\": 16000250}\n]\n"
+        );
+        assert_eq!(
+            Format::from_path(Path::new("bench/2019.json")),
+            Ok(Format::Json)
+        );
+        assert!(Format::from_path(Path::new("timings.txt")).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+//! Solves the fixture of every day with recorded answers and compares them, so that changes to
+//! shared code like `intcode` or `lib::Grid` cannot silently break old days.
+
+use aoc::answers::{self, Answers, Check};
+use aoc::days;
+use lib::input::input_id;
+use std::fs;
+
+fn check(day: u8) {
+    let answers = Answers::load(&answers::workspace_path(answers::ANSWERS_FILE)).unwrap();
+    let fixture = answers::workspace_path(answers::FIXTURE_DIR).join(format!("day{:02}.txt", day));
+    let input = fs::read_to_string(&fixture).unwrap();
+    let entry = days::find(day).unwrap();
+
+    let parts = answers.parts(day, &input_id(&input));
+    assert!(!parts.is_empty(), "No answers for {}", fixture.display());
+    for outcome in answers::verify(entry, &input, &parts, &answers).unwrap() {
+        assert_eq!(
+            outcome.check,
+            Check::Correct,
+            "Day {}, {}: {}",
+            day,
+            outcome.part,
+            outcome.answer
+        );
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/regression.rs"));
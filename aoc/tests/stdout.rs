@@ -0,0 +1,43 @@
+//! Runs days which log or draw while solving and checks that stdout still only holds answers,
+//! so that `aoc run all` output can be read by scripts.
+
+use aoc::answers;
+use std::process::Command;
+
+fn check(day: u8, args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", &day.to_string(), "--fixtures"])
+        .args(args)
+        .current_dir(answers::workspace_path(""))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "Day {} {:?} failed", day, args);
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let prefix = format!("Day {:02}, Part ", day);
+    assert_eq!(
+        stdout.lines().count(),
+        2,
+        "Day {} {:?}: {:?}",
+        day,
+        args,
+        stdout
+    );
+    for line in stdout.lines() {
+        assert!(
+            line.starts_with(&prefix),
+            "Day {} {:?}: {:?}",
+            day,
+            args,
+            line
+        );
+    }
+}
+
+#[test]
+fn test_only_answers_on_stdout() {
+    for &day in &[1, 13, 17, 24] {
+        check(day, &[]);
+        check(day, &["--log", "trace"]);
+    }
+}
@@ -0,0 +1,66 @@
+use lib::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, String> {
+        lib::input::parse_lines(input)
+    }
+
+    fn part1(&self, input: &Vec<i32>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Vec<i32>) -> i32 {
+        part2(input)
+    }
+}
+
+fn part1(input: &[i32]) -> i32 {
+    input
+        .iter()
+        .map(mass_to_fuel)
+        .sum()
+}
+
+fn mass_to_fuel(mass: &i32) -> i32 {
+    (mass / 3) - 2
+}
+
+fn part2(input: &[i32]) -> i32 {
+    input
+        .iter()
+        .map(mass_and_fuel_to_fuel)
+        .sum()
+}
+
+fn mass_and_fuel_to_fuel(module_mass: &i32) -> i32 {
+    // Calculate fuel for module mass
+    let module_fule = mass_to_fuel(module_mass);
+
+    let mut total_fuel = module_fule;
+    let mut current_mass = module_fule;
+
+    loop {
+        let fuel = mass_to_fuel(&current_mass);
+        if fuel <= 0 {
+            break;
+        }
+        total_fuel += fuel;
+        current_mass = fuel;
+        lib::trace!("{:?}", fuel);
+    }
+
+    total_fuel
+}
+
+#[test]
+fn test_add() {
+    assert_eq!(mass_and_fuel_to_fuel(&14), 2);
+    assert_eq!(mass_and_fuel_to_fuel(&1969), 966);
+    assert_eq!(mass_and_fuel_to_fuel(&100756), 50346);
+}
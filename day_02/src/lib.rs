@@ -0,0 +1,83 @@
+use lib::solution::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, input: &Vec<usize>) -> usize {
+        let output = part1(&input_for_parameters(input, 12, 2));
+        output[0]
+    }
+
+    fn part2(&self, input: &Vec<usize>) -> usize {
+        let (noun, verb) = part2(input, 19690720);
+        100 * noun + verb
+    }
+}
+
+fn part1(input: &[usize]) -> Vec<usize> {
+    let mut input = input.to_owned();
+
+    let mut pos = 0;
+    loop {
+        let next = input[pos];
+        match next {
+            1 => {
+                let op1 = input[pos+1];
+                let op2 = input[pos+2];
+                let target_idx = input[pos+3];
+                input[target_idx] = input[op1] + input[op2];
+                pos += 4;
+            },
+            2 => {
+                let op1 = input[pos+1];
+                let op2 = input[pos+2];
+                let target_idx = input[pos+3];
+                input[target_idx] = input[op1] * input[op2];
+                pos += 4;
+            },
+            99 => break,
+            _ => unreachable!()
+        }
+    }
+
+    input
+}
+
+fn input_for_parameters(input: &[usize], p1: usize, p2: usize) -> Vec<usize> {
+    let mut input = input.to_owned();
+    input[1] = p1;
+    input[2] = p2;
+    input
+}
+
+fn part2(base_input: &[usize], solution: i32) -> (usize, usize) {
+    let mut params = (0usize, 0usize);
+    for x in 0..100usize {
+        for y in 0..100usize {
+            let input = input_for_parameters(base_input, x, y);
+            let output = part1(&input);
+            let result = output.first().unwrap();
+            if *result == solution as usize {
+                params = (x, y);
+                break
+            }
+        }
+    }
+    params
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(part1(&[1, 0, 0, 0, 99]), vec![2, 0, 0, 0, 99]);
+    assert_eq!(part1(&[2, 3, 0, 3, 99]), vec![2, 3, 0, 6, 99]);
+    assert_eq!(part1(&[2, 4, 4, 5, 99, 0]), vec![2, 4, 4, 5, 99, 9801]);
+    assert_eq!(part1(&[1, 1, 1, 4, 99, 5, 6, 0, 99]), vec![30, 1, 1, 4, 2, 5, 6, 0, 99]);
+}
@@ -0,0 +1,249 @@
+use lib::geom::{Direction, Point2};
+use lib::solution::Solution;
+use std::ops::Range;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = (Vec<Point>, Vec<Point>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, String> {
+        let mut cables = lib::input::lines(input).map(|cable| vectors_to_points(parse_cable(cable)));
+        let mut next_cable = || cables.next().unwrap_or_else(|| Err(String::from("Expected two cables")));
+        Ok((next_cable()?, next_cable()?))
+    }
+
+    fn part1(&self, (cable1_points, cable2_points): &Self::Input) -> usize {
+        let intersections = find_intersections(&mut cable1_points.clone(), &mut cable2_points.clone());
+        manhattan_distance(intersections.first().unwrap())
+    }
+
+    fn part2(&self, (cable1_points, cable2_points): &Self::Input) -> usize {
+        let intersections = find_intersections(&mut cable1_points.clone(), &mut cable2_points.clone());
+        find_fastest_intersection(&intersections, cable1_points, cable2_points)
+    }
+}
+
+fn find_fastest_intersection(intersections: &[Point], cable1_points: &[Point], cable2_points: &[Point]) -> usize {
+    let mut path_lengths = intersections
+        .iter()
+        .map(|int| {
+            get_path_length(cable1_points, int) + get_path_length(cable2_points, int)
+        })
+        .collect::<Vec<usize>>();
+    path_lengths.sort();
+    *path_lengths.first().unwrap()
+}
+
+fn get_path_length(path: &[Point], point: &Point) -> usize {
+    path.iter().position(|x| x == point).unwrap()
+}
+
+type Point = Point2<isize>;
+
+/// Distance from the central port, where both cables start.
+fn manhattan_distance(point: &Point) -> usize {
+    point.manhattan(Point::default()) as usize
+}
+
+fn find_intersections(
+    cable1_points: &mut [Point],
+    cable2_points: &mut [Point],
+) -> Vec<Point> {
+    cable1_points.sort();
+    cable2_points.sort();
+
+    let mut intersections = vec![];
+
+    let mut x1 = 0;
+    let mut x2 = 0;
+
+    loop {
+        let cable1_p = cable1_points.get(x1);
+        let cable2_p = cable2_points.get(x2);
+
+        match (cable1_p, cable2_p) {
+            (Some(cp1), Some(cp2)) => {
+                if cp1.x == 0 && cp2.x == 0 && cp1.y == 0 && cp2.y == 0 {
+                    x1 += 1;
+                    x2 += 1;
+                } else if cp1.eq(cp2) {
+                    intersections.push(*cp1);
+                    x1 += 1;
+                } else if cp1.gt(cp2) {
+                    x2 += 1;
+                } else {
+                    x1 += 1;
+                }
+            }
+            _ => break,
+        }
+    }
+
+    intersections.sort_by_key(manhattan_distance);
+    intersections
+}
+
+fn vectors_to_points(vectors: Vec<&str>) -> Result<Vec<Point>, String> {
+    let mut points: Vec<Point> = vec![Point::new(0, 0)];
+
+    for vector in vectors {
+        let starting_point = *points.last().unwrap();
+        let (direction, range) = parse_vector(vector)?;
+        let mut new_points = match direction {
+            Direction::North => {
+                range
+                    .map(|delta| Point::new(starting_point.x, starting_point.y + delta))
+                    .collect::<Vec<Point>>()
+            }
+            Direction::East => {
+                range
+                    .map(|delta| Point::new(starting_point.x + delta, starting_point.y))
+                    .collect::<Vec<Point>>()
+            }
+            Direction::West => {
+                range
+                    .map(|delta| Point::new(starting_point.x + delta, starting_point.y))
+                    .collect::<Vec<Point>>()
+            }
+            Direction::South => {
+                range
+                    .map(|delta| Point::new(starting_point.x, starting_point.y + delta))
+                    .collect::<Vec<Point>>()
+            }
+        };
+
+        match direction {
+            Direction::South | Direction::West => new_points.reverse(),
+            _ => ()
+        };
+        points.extend(new_points.iter());
+    }
+
+    Ok(points)
+}
+
+fn parse_vector(vector: &str) -> Result<(Direction, Range<isize>), String> {
+    let invalid = || format!("Invalid vector '{}'", vector);
+    let direction = vector.get(..1).ok_or_else(invalid)?;
+    let steps = vector[1..].parse::<u32>().map_err(|_| invalid())? as isize;
+
+    match direction {
+        "U" => Ok((Direction::North, 1..(steps + 1))),
+        "D" => Ok((Direction::South, -steps..0)),
+        "L" => Ok((Direction::West, -steps..0)),
+        "R" => Ok((Direction::East, 1..(steps + 1))),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_cable(input: &str) -> Vec<&str> {
+    input.split(",").map(|x| x.trim()).collect::<Vec<&str>>()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn test_vectors_to_points() {
+        assert_eq!(
+            vectors_to_points(vec!["D1", "R1", "L1", "U3"]),
+            Ok(vec![
+                Point::new(0, 0),
+                Point::new(0, -1),
+                Point::new(1, -1),
+                Point::new(0, -1),
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+            ])
+        );
+        assert_eq!(vectors_to_points(vec!["D1", "X2"]), Err(String::from("Invalid vector 'X2'")));
+        assert_eq!(vectors_to_points(vec!["U"]), Err(String::from("Invalid vector 'U'")));
+    }
+
+    #[test]
+    fn test_point_ordering() {
+        let mut points = vec![Point::new(0, 2), Point::new(1, 0), Point::new(-5, -1)];
+        let expected = vec![Point::new(-5, -1), Point::new(0, 2), Point::new(1, 0)];
+        points.sort();
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn test_point_equality() {
+        let p1 = Point::new(0, 0);
+        let p2 = Point::new(1, 0);
+        let p3 = Point::new(0, 0);
+
+        assert_ne!(p1, p2);
+        assert_eq!(p1, p3);
+    }
+
+    #[test]
+    fn test_find_intersections() {
+        let input = vec![
+            (
+                "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51",
+                "U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+                135,
+            ),
+            (
+                "R75,D30,R83,U83,L12,D49,R71,U7,L72",
+                "U62,R66,U55,R34,D71,R55,D58,R83",
+                159,
+            ),
+        ];
+
+        for (left, right, expected_distance) in input {
+            let cable1 = parse_cable(left);
+            let cable2 = parse_cable(right);
+
+            let mut cable1_points = vectors_to_points(cable1).unwrap();
+            let mut cable2_points = vectors_to_points(cable2).unwrap();
+
+            let intersections = find_intersections(&mut cable1_points, &mut cable2_points);
+
+            let first_intersection = intersections.first().unwrap();
+            assert_eq!(
+                manhattan_distance(first_intersection),
+                expected_distance
+            );
+        }
+    }
+
+
+    #[test]
+    fn test_find_intersections_small() {
+        let input = vec![
+            (
+                "R3,U3",
+                "U2,R5",
+                5,
+            ),
+            (
+                "L3,D3",
+                "D2,L5",
+                5
+            )
+        ];
+
+        for (left, right, expected_distance) in input {
+            let cable1 = parse_cable(left);
+            let cable2 = parse_cable(right);
+
+            let mut cable1_points = vectors_to_points(cable1).unwrap();
+            let mut cable2_points = vectors_to_points(cable2).unwrap();
+
+            let intersections = find_intersections(&mut cable1_points, &mut cable2_points);
+            let first_intersection = intersections.first().unwrap();
+            assert_eq!(
+                manhattan_distance(first_intersection),
+                expected_distance
+            );
+        }
+    }
+}
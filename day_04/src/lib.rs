@@ -0,0 +1,103 @@
+use lib::solution::Solution;
+use std::ops::RangeInclusive;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = RangeInclusive<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<RangeInclusive<usize>, String> {
+        let invalid = || format!("Expected a range like '100-200', found '{}'", input.trim());
+        let (start, end) = input.trim().split_once('-').ok_or_else(invalid)?;
+        let parse = |bound: &str| bound.parse::<usize>().map_err(|_| invalid());
+        Ok(parse(start)?..=parse(end)?)
+    }
+
+    fn part1(&self, input: &RangeInclusive<usize>) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(&self, input: &RangeInclusive<usize>) -> usize {
+        part2(input.clone())
+    }
+}
+
+fn part1(input: RangeInclusive<usize>) -> usize {
+    let valid_passwords = input
+        .filter(|x: &usize| is_valid_part1(*x))
+        .collect::<Vec<usize>>();
+
+    valid_passwords.len()
+}
+
+fn part2(input: RangeInclusive<usize>) -> usize {
+    let valid_passwords = input
+        .filter(|x: &usize| is_valid_part2(*x))
+        .collect::<Vec<usize>>();
+
+    valid_passwords.len()
+}
+
+fn is_valid_part1(input: usize) -> bool {
+    rule_contains_double(input) && rule_never_decreasing(input)
+}
+
+fn is_valid_part2(input: usize) -> bool {
+    is_valid_part1(input) && rule_double_group_required(input)
+}
+
+fn rule_never_decreasing(input: usize) -> bool {
+    let chars = input.to_string().chars().collect::<Vec<char>>();
+    let unsorted_chars = chars.clone();
+    let mut sorted_chars = chars.clone();
+    sorted_chars.sort();
+
+    let n_matching_chars = unsorted_chars
+        .iter()
+        .zip(&sorted_chars)
+        .filter(|&(a, b)| a == b)
+        .count();
+
+    n_matching_chars == chars.len()
+}
+
+fn rule_contains_double(input: usize) -> bool {
+    let chars = input.to_string().chars().collect::<Vec<char>>();
+    let mut unique_chars = chars.clone();
+    unique_chars.dedup();
+
+    chars.len() != unique_chars.len()
+}
+
+fn rule_double_group_required(input: usize) -> bool {
+    let chars = input.to_string().chars().collect::<Vec<char>>();
+
+    type OccurrenceMap = std::collections::HashMap<char, usize>;
+
+    let occurences = chars.iter().fold(
+        std::collections::HashMap::new(),
+        |mut acc: OccurrenceMap, &character| {
+            acc.entry(character).and_modify(|x| *x += 1).or_insert(1);
+            acc
+        },
+    );
+
+    let n_double_groups = occurences.values().filter(|x| **x == 2).count();
+    n_double_groups > 0
+}
+
+#[test]
+fn test_is_valid_part1() {
+    assert!(is_valid_part1(111_111));
+    assert!(!is_valid_part1(223_450));
+    assert!(!is_valid_part1(123_789));
+}
+
+#[test]
+fn test_is_valid_part2() {
+    assert!(is_valid_part2(112_233));
+    assert!(!is_valid_part2(123_444));
+    assert!(is_valid_part2(111_122));
+}
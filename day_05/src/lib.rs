@@ -0,0 +1,30 @@
+use intcode::{IntCodeComputer, Memory, MemoryValue};
+use lib::solution::Solution;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Memory;
+    type Answer1 = MemoryValue;
+    type Answer2 = MemoryValue;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, memory: &Memory) -> MemoryValue {
+        run_diagnostics(memory, 1)
+    }
+
+    fn part2(&self, memory: &Memory) -> MemoryValue {
+        run_diagnostics(memory, 5)
+    }
+}
+
+/// Runs the diagnostic program for the system with the given ID, which outputs a diagnostic
+/// code after all of its tests.
+fn run_diagnostics(memory: &Memory, system_id: MemoryValue) -> MemoryValue {
+    let mut comp = IntCodeComputer::new(memory.clone());
+    comp.run(vec![system_id]);
+    *comp.get_output().last().unwrap()
+}
@@ -0,0 +1,140 @@
+use lib::search::{self, Bfs};
+use lib::solution::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = OrbitGraph;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<OrbitGraph, String> {
+        let orbits = parse_orbits(&lib::input::lines(input).collect())?;
+        let graph = build_orbit_graph(orbits);
+        match ["COM", "YOU", "SAN"].iter().find(|object| !graph.contains_key(**object)) {
+            Some(object) => Err(format!("The map does not mention {}", object)),
+            None => Ok(graph),
+        }
+    }
+
+    fn part1(&self, graph: &OrbitGraph) -> usize {
+        count_total_orbits(graph)
+    }
+
+    fn part2(&self, graph: &OrbitGraph) -> usize {
+        find_shortest_path_length(graph, String::from("YOU"), String::from("SAN"))
+    }
+}
+
+struct Orbit {
+    parent: String,
+    orbiter: String,
+}
+
+type OrbitGraph = std::collections::HashMap::<String, Vec<String>>;
+
+fn parse_orbits(input: &Vec<&str>) -> Result<Vec<Orbit>, String> {
+    input
+        .iter()
+        .map(|string| {
+            let (parent, orbiter) = string
+                .split_once(')')
+                .ok_or_else(|| format!("Expected an orbit like 'A)B', found '{}'", string))?;
+
+            Ok(Orbit {
+                parent: parent.to_string(),
+                orbiter: orbiter.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn add_orbit_graph_edge(orbit_graph: &mut OrbitGraph, start: String, end: String) {
+    match orbit_graph.get_mut(&start) {
+        Some(orbiters) => {
+            orbiters.push(end.to_string());
+        }
+        None => {
+            let orbiters = vec![end.to_string()];
+            orbit_graph.insert(start, orbiters);
+        }
+    }
+}
+
+fn build_orbit_graph(orbits: Vec<Orbit>) -> OrbitGraph {
+    let mut graph = OrbitGraph::new();
+
+    for orbit in orbits {
+        add_orbit_graph_edge(&mut graph, orbit.orbiter.clone(), orbit.parent.clone());
+        add_orbit_graph_edge(&mut graph, orbit.parent.clone(), orbit.orbiter.clone());
+    }
+
+    graph
+}
+
+fn find_shortest_path_length(graph: &OrbitGraph, start: String, target: String) -> usize {
+    let neighbours = |planet: &String| graph.get(planet).cloned().unwrap_or_default();
+    let (_, hops) = search::bfs(start, neighbours, |planet| planet.eq(&target)).unwrap();
+
+    // Transfers happen between the objects YOU and SAN orbit, not the two of them
+    hops - 2
+}
+
+fn count_total_orbits(graph: &OrbitGraph) -> usize {
+    let neighbours = |planet: &String| graph.get(planet).cloned().unwrap_or_default();
+    Bfs::new(String::from("COM"), neighbours)
+        .map(|(_, depth)| depth)
+        .sum()
+}
+
+#[cfg(test)]
+fn test_input() -> Vec<&'static str> {
+    vec![
+        "COM)B",
+        "B)C",
+        "C)D",
+        "D)E",
+        "E)F",
+        "B)G",
+        "G)H",
+        "D)I",
+        "E)J",
+        "J)K",
+        "K)L",
+    ]
+}
+
+#[cfg(test)]
+fn test_input2() -> Vec<&'static str> {
+    vec![
+        "COM)B",
+        "B)C",
+        "C)D",
+        "D)E",
+        "E)F",
+        "B)G",
+        "G)H",
+        "D)I",
+        "E)J",
+        "J)K",
+        "K)L",
+        "K)YOU",
+        "I)SAN",
+    ]
+}
+
+#[test]
+fn test_part1() {
+    let test_input = test_input();
+    let graph = build_orbit_graph(parse_orbits(&test_input).unwrap());
+    assert_eq!(count_total_orbits(&graph), 42);
+}
+
+#[test]
+fn test_part2() {
+    let test_input = test_input2();
+    let orbits = parse_orbits(&test_input).unwrap();
+    let graph = build_orbit_graph(orbits);
+    let shortest_path = find_shortest_path_length(&graph, String::from("YOU"), String::from("SAN"));
+    assert_eq!(shortest_path, 4);
+}
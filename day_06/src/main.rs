@@ -1,1616 +1,5 @@
-use std::collections::HashSet;
+use day_06::Day06;
 
 fn main() {
-    let input = input();
-    let n_total_orbits = part1(&input);
-    println!("Part 1 Total Orbits: {:?}", n_total_orbits);
-
-    let shortest_path_length = part2(&input);
-    println!("Part 2 Shortest Path: {:?}", shortest_path_length);
-}
-
-fn part1(input: &Vec<&str>) -> usize {
-    let orbits = parse_orbits(input);
-    let graph = build_orbit_graph(orbits);
-    count_total_orbits(&graph)
-}
-
-fn part2(input: &Vec<&str>) -> usize {
-    let orbits = parse_orbits(input);
-    let graph = build_orbit_graph(orbits);
-    let shortest_path = find_shortest_path_length(&graph, String::from("YOU"), String::from("SAN"));
-    shortest_path
-}
-
-struct Orbit {
-    parent: String,
-    orbiter: String,
-}
-
-type OrbitGraph = std::collections::HashMap::<String, Vec<String>>;
-
-fn parse_orbits(input: &Vec<&str>) -> Vec<Orbit> {
-    input
-        .into_iter()
-        .map(|string| {
-            let parts = string
-                .split(')')
-                .collect::<Vec<&str>>();
-
-            Orbit {
-                parent: parts.get(0).unwrap().parse().unwrap(),
-                orbiter: parts.get(1).unwrap().parse().unwrap(),
-            }
-        })
-        .collect()
-}
-
-fn add_orbit_graph_edge(orbit_graph: &mut OrbitGraph, start: String, end: String) {
-    match orbit_graph.get_mut(&start) {
-        Some(orbiters) => {
-            orbiters.push(end.to_string());
-        }
-        None => {
-            let orbiters = vec![end.to_string()];
-            orbit_graph.insert(start, orbiters);
-        }
-    }
-}
-
-fn build_orbit_graph(orbits: Vec<Orbit>) -> OrbitGraph {
-    let mut graph = OrbitGraph::new();
-
-    for orbit in orbits {
-        add_orbit_graph_edge(&mut graph, orbit.orbiter.clone(), orbit.parent.clone());
-        add_orbit_graph_edge(&mut graph, orbit.parent.clone(), orbit.orbiter.clone());
-    }
-
-    graph
-}
-
-fn find_shortest_path_length(graph: &OrbitGraph, start: String, target: String) -> usize {
-    let mut queue = std::collections::vec_deque::VecDeque::new();
-    let mut distances = std::collections::HashMap::new();
-    let mut seen_planets = HashSet::new();
-
-    queue.push_front(start.clone());
-    distances.insert(start.clone(), 0 as usize);
-
-    while let Some(next) = queue.pop_back() {
-        seen_planets.insert(next.clone());
-        let distance = distances.get(&next).unwrap().clone();
-        if let Some(neighbours) = graph.get(&next) {
-            neighbours.iter().for_each(|n| {
-                if !seen_planets.contains(n) {
-                    queue.push_front(n.to_owned());
-                }
-                let new_distance = distance + 1;
-                match distances.get_mut(n) {
-                    Some(v) => { *v = std::cmp::min(v.to_owned(), new_distance); }
-                    _ => { distances.insert(n.to_owned(), distance + 1); }
-                }
-            });
-        }
-    }
-
-    distances.get(&target).unwrap().clone() - 2
-}
-
-fn count_total_orbits(graph: &OrbitGraph) -> usize {
-    let mut distances = std::collections::HashMap::<String, usize>::new();
-    let mut unseen: Vec<String> = vec![String::from("COM")];
-    let mut seen_planets = HashSet::new();
-
-    while let Some(parent) = unseen.pop() {
-        seen_planets.insert(parent.clone());
-        if let Some(neighbours) = graph.get(&parent) {
-            for neighbour in neighbours {
-                if !seen_planets.contains(neighbour) {
-                    let depth = distances.get(&parent).unwrap_or(&0).clone();
-                    distances.insert(neighbour.clone(), depth + 1);
-                    unseen.push(neighbour.clone());
-                }
-            }
-        }
-    }
-
-    distances.values().sum()
-}
-
-fn input() -> Vec<&'static str> {
-    let input = vec![
-        "CWG)HRQ",
-        "D4M)NR4",
-        "ZH2)14W",
-        "326)55X",
-        "88J)W92",
-        "KF2)1D9",
-        "J6C)BGJ",
-        "9GS)19J",
-        "X17)JJ4",
-        "PRC)PQV",
-        "9CB)YPG",
-        "RVH)W76",
-        "KX9)4TD",
-        "J88)HWB",
-        "461)CG1",
-        "Z1V)MZJ",
-        "SBV)FF7",
-        "VTP)51F",
-        "C2Q)MDM",
-        "VHG)M6M",
-        "V1L)5KK",
-        "VTT)59M",
-        "1FF)N6S",
-        "PQ8)Y26",
-        "PBH)17V",
-        "K7T)NTX",
-        "4JQ)SGJ",
-        "T5V)ZDD",
-        "2CD)JC7",
-        "62B)BNX",
-        "BGJ)M47",
-        "B7Z)DQT",
-        "ZLV)FYS",
-        "9DD)YNF",
-        "YKS)Z5Z",
-        "QKS)8D1",
-        "7DJ)NGN",
-        "3LY)DCB",
-        "L4Z)T41",
-        "KJV)9NT",
-        "9LV)R55",
-        "D81)W1F",
-        "VF4)H7S",
-        "VPD)WFR",
-        "TRL)X6N",
-        "BTY)TRH",
-        "WJ8)VZ8",
-        "2W5)58F",
-        "91N)H3G",
-        "CJK)VTP",
-        "JJX)F92",
-        "LS5)9DQ",
-        "GSY)B7X",
-        "641)PLK",
-        "SPK)HVG",
-        "12V)MTZ",
-        "N34)72T",
-        "JRP)S8J",
-        "QYW)9RW",
-        "FZX)XK8",
-        "YBR)1FF",
-        "GZJ)DB7",
-        "8NZ)HT5",
-        "X36)DRX",
-        "NWX)L5Q",
-        "38F)98X",
-        "9GS)6P9",
-        "1RG)DDY",
-        "P33)7C2",
-        "QFW)WQ1",
-        "SZQ)YYS",
-        "WC9)K8Q",
-        "FB2)FV2",
-        "T55)NW3",
-        "JP7)TZ5",
-        "4HK)YT3",
-        "835)TCH",
-        "C92)4JQ",
-        "DQT)B6W",
-        "FR1)HD3",
-        "TKT)4NV",
-        "QJ5)2Q7",
-        "3DB)BSR",
-        "QLS)Q7G",
-        "FLZ)RXZ",
-        "64Y)DYQ",
-        "QHH)M5H",
-        "3X8)L12",
-        "W9W)DV7",
-        "MJJ)GJJ",
-        "NGN)H7F",
-        "1X6)SYV",
-        "TQS)3DC",
-        "H22)GZ9",
-        "8VZ)BX2",
-        "PRC)4LM",
-        "T8H)L28",
-        "HSB)FS2",
-        "87Z)NR6",
-        "MYS)2CJ",
-        "FZ8)YC8",
-        "Z26)B38",
-        "65J)N66",
-        "TDK)SJD",
-        "RL1)ZR6",
-        "47M)19R",
-        "NCK)4GD",
-        "1H4)Y8Z",
-        "3PZ)DXC",
-        "BR8)YY3",
-        "JZF)YKS",
-        "3JS)7DJ",
-        "CX7)VPD",
-        "YCF)9XQ",
-        "DMT)YX9",
-        "FDS)DY3",
-        "1ZV)F2S",
-        "SRW)V2N",
-        "Q7R)K9L",
-        "VRV)39W",
-        "LPZ)VYB",
-        "DYQ)X4M",
-        "61R)XGB",
-        "TK1)TR8",
-        "X6N)KPR",
-        "HRQ)64T",
-        "M1J)YH5",
-        "9CQ)HYB",
-        "PB1)KR7",
-        "VKY)89C",
-        "PMR)C9K",
-        "L16)XXD",
-        "5FM)Q8Z",
-        "6JG)WV7",
-        "VDC)4BP",
-        "Y26)HLF",
-        "41H)HBQ",
-        "RV3)QHN",
-        "GT3)XRH",
-        "SNK)MG1",
-        "Q8P)TSL",
-        "WQ1)N9R",
-        "466)DT2",
-        "B65)276",
-        "MVK)QM9",
-        "YWZ)D4M",
-        "N2K)NJP",
-        "7WY)D4R",
-        "W59)7J1",
-        "78X)LZH",
-        "P74)23V",
-        "LB8)L75",
-        "M3Z)Y8C",
-        "6W5)NSV",
-        "7HK)KVF",
-        "N47)ZP9",
-        "B3H)ZR9",
-        "VML)MQT",
-        "1VQ)RJY",
-        "XSJ)5J5",
-        "XRW)5MY",
-        "LP1)1F7",
-        "VGM)PH2",
-        "62L)5R7",
-        "99M)R8T",
-        "B7X)MYS",
-        "XM4)PB1",
-        "W5P)7JS",
-        "Y33)VNH",
-        "FJ4)WQH",
-        "CFQ)L68",
-        "4DX)PM7",
-        "BC3)J9X",
-        "PBT)DHZ",
-        "DYC)MRF",
-        "PKS)L5F",
-        "BXF)D6B",
-        "JKK)CX7",
-        "K4Z)W7B",
-        "DSH)B1Q",
-        "M86)8J6",
-        "XQD)MZY",
-        "S35)CF2",
-        "ZYX)BJ6",
-        "RY2)LZ3",
-        "4YB)M61",
-        "7WS)7HK",
-        "FRW)PZX",
-        "YYS)2NP",
-        "QJP)G8D",
-        "HYH)157",
-        "DXG)Y98",
-        "XXD)8BP",
-        "55X)CNT",
-        "4YB)1D1",
-        "DST)SHD",
-        "FDX)Z8C",
-        "1D1)NYL",
-        "131)F8H",
-        "LQF)T68",
-        "RN3)1X7",
-        "JK9)W5P",
-        "MXK)4LJ",
-        "CV9)CNP",
-        "2HR)N75",
-        "KX9)1WJ",
-        "YY3)JWB",
-        "VR5)PKN",
-        "JJ4)3JH",
-        "43W)2Y6",
-        "NBV)3MY",
-        "YJ8)3C1",
-        "XGQ)2ZH",
-        "J3R)6DY",
-        "V2N)D8K",
-        "CD1)N2K",
-        "9GJ)B8M",
-        "MP8)G7Q",
-        "LHC)D81",
-        "77W)MDN",
-        "HK3)8W2",
-        "ZR6)7GW",
-        "RYG)F58",
-        "2VG)DY9",
-        "C4S)JSN",
-        "HPT)PGP",
-        "NJZ)9TL",
-        "JK4)268",
-        "W3F)X8S",
-        "XYF)HYH",
-        "WXS)6FP",
-        "DSS)SD4",
-        "1TX)SDD",
-        "YNY)6W2",
-        "23V)XLZ",
-        "K1H)RB3",
-        "V99)261",
-        "1WJ)S98",
-        "P1S)96D",
-        "PCT)XJZ",
-        "2NP)XY4",
-        "16D)YPC",
-        "7LK)JNY",
-        "7J1)41N",
-        "6PX)QQC",
-        "FCL)XWM",
-        "DRX)RQ1",
-        "KLR)67C",
-        "2YD)FMT",
-        "HVG)QWT",
-        "T78)WL8",
-        "NFF)3LY",
-        "M47)Z26",
-        "HM6)TCL",
-        "1F7)N34",
-        "SDD)2B6",
-        "9SS)1HN",
-        "B1Q)C4S",
-        "9PB)DMT",
-        "MDL)RG8",
-        "WV7)2XP",
-        "3M6)MLZ",
-        "V8H)NCK",
-        "VHL)6XK",
-        "5KR)8VZ",
-        "QF9)S4P",
-        "84J)QW6",
-        "KDH)PHX",
-        "DQ1)ZDC",
-        "GVY)ZVD",
-        "PSQ)LXJ",
-        "XJZ)4W6",
-        "DV7)MK7",
-        "S4N)ZBH",
-        "51F)D9Q",
-        "18J)LZZ",
-        "V58)181",
-        "QTZ)428",
-        "82F)KTS",
-        "7LP)YK6",
-        "QJ5)47M",
-        "4RJ)RVX",
-        "DF6)7B2",
-        "NWL)1TX",
-        "2Y6)VR5",
-        "69W)574",
-        "T83)ZTW",
-        "JGG)Y33",
-        "VXH)JS2",
-        "ZZ8)TKT",
-        "BS1)YH6",
-        "33G)XR2",
-        "FFQ)W2L",
-        "WLM)5SS",
-        "2ZG)HH8",
-        "7DN)7L8",
-        "J9X)91N",
-        "DCB)XYL",
-        "LV1)W6S",
-        "BKZ)LQ6",
-        "4KG)1RG",
-        "TCH)PM2",
-        "DB1)S4N",
-        "5Y1)SZQ",
-        "48X)FZ8",
-        "NQ3)2HH",
-        "VC1)DGN",
-        "VN3)9X9",
-        "3NK)BGB",
-        "M55)VF4",
-        "62S)14R",
-        "ZCV)YLQ",
-        "574)NSX",
-        "ZX9)9PT",
-        "1D9)466",
-        "BNX)WGD",
-        "YK6)9DD",
-        "49M)53M",
-        "CRC)MR7",
-        "QBX)J13",
-        "BD4)3ZX",
-        "19C)SP2",
-        "TFZ)Z6N",
-        "PJW)Q8K",
-        "92C)NRB",
-        "W3S)M4D",
-        "8NM)64D",
-        "6CR)DB1",
-        "7HK)5V4",
-        "W31)YGJ",
-        "X1S)P4F",
-        "YNZ)WCF",
-        "WRY)1ML",
-        "SXY)CC3",
-        "K1W)3KW",
-        "8BM)B45",
-        "RY2)PFC",
-        "CW9)PVZ",
-        "RR7)131",
-        "74S)8BF",
-        "MQT)8YV",
-        "27N)MSN",
-        "1H4)W9W",
-        "YTZ)Z17",
-        "L8F)M2L",
-        "WQH)G54",
-        "PM5)SNM",
-        "42J)KMX",
-        "V9H)G22",
-        "NNW)S39",
-        "GW7)MXJ",
-        "2ZZ)Y9R",
-        "VW5)GCD",
-        "X4D)FFK",
-        "S6X)7J4",
-        "B21)SKX",
-        "MQ6)G9P",
-        "M6V)Y6N",
-        "9X9)Z7Z",
-        "YNY)1ZV",
-        "KB3)FRW",
-        "DFL)2CN",
-        "M42)P58",
-        "CYW)VHG",
-        "V38)4S3",
-        "1L5)QBX",
-        "Q3R)YY2",
-        "5LN)62L",
-        "R9Z)JN7",
-        "KHP)6JG",
-        "7CW)BW3",
-        "NBL)VDC",
-        "789)T88",
-        "SRJ)2DW",
-        "WRW)ZG5",
-        "Y7Q)ZQW",
-        "H5S)QYZ",
-        "W7B)1L5",
-        "PLK)LB8",
-        "KHK)9PW",
-        "JMW)SYT",
-        "HC1)KBB",
-        "YLQ)9GS",
-        "F52)J7M",
-        "HBH)KHP",
-        "5MY)41W",
-        "2W5)F8X",
-        "F58)B1V",
-        "PTQ)V8H",
-        "T8W)RQF",
-        "W95)KF2",
-        "LJS)5FM",
-        "2CN)KXY",
-        "YPC)4ND",
-        "VN3)GB3",
-        "LZZ)VGD",
-        "Q5S)WLM",
-        "DLT)6PX",
-        "151)6PC",
-        "Z7Z)JKQ",
-        "QYC)VH8",
-        "DZJ)QVQ",
-        "5RR)42J",
-        "94M)JN3",
-        "W16)835",
-        "71Y)L2D",
-        "X4Z)NBT",
-        "8VP)RFG",
-        "KPR)QYC",
-        "RRY)CVK",
-        "8JD)MZK",
-        "2BN)H4N",
-        "N3Y)YPP",
-        "1J2)94M",
-        "C9K)NVM",
-        "14R)2HT",
-        "ZZD)PTB",
-        "82C)YTC",
-        "LWF)386",
-        "Z77)9SK",
-        "C7R)CD5",
-        "ZTW)KV6",
-        "6W2)P52",
-        "YTS)BR8",
-        "62L)8VS",
-        "XLG)2D4",
-        "3C1)KDH",
-        "MDN)FDS",
-        "1HY)31T",
-        "77W)6YX",
-        "YM8)QF9",
-        "XR2)YRD",
-        "H4T)YHN",
-        "S3G)QCN",
-        "D2Q)1N6",
-        "NBX)C4M",
-        "JQW)VK8",
-        "3PZ)64Y",
-        "2WM)H21",
-        "DPJ)HQV",
-        "18Z)9BY",
-        "N8F)65J",
-        "G1W)38F",
-        "MQT)H5S",
-        "YNV)1YL",
-        "K63)VG7",
-        "GDS)HK3",
-        "H3L)MYQ",
-        "8JN)YKL",
-        "VHZ)KDV",
-        "MK7)PQ8",
-        "TPL)7LP",
-        "63R)PQ2",
-        "13Y)HVV",
-        "P58)8BM",
-        "JF1)47V",
-        "MFY)HHW",
-        "FSZ)4YB",
-        "N2K)GHQ",
-        "KPK)8HS",
-        "4PY)4G3",
-        "VK8)SBK",
-        "7L8)S4Q",
-        "P52)Z3T",
-        "M61)ZK7",
-        "QHF)JK4",
-        "27J)4P6",
-        "RXB)DSH",
-        "NW3)LC2",
-        "H3G)SWK",
-        "FZL)NBV",
-        "L4F)XGQ",
-        "FDP)QXJ",
-        "Z9H)7M6",
-        "VKY)R1Q",
-        "P14)NGF",
-        "8J7)Q6H",
-        "KQM)RR7",
-        "NSV)LKF",
-        "Y6N)P62",
-        "K7B)CYW",
-        "ZDP)W95",
-        "CD5)Y6T",
-        "C5K)ZM7",
-        "TZ5)LWF",
-        "MG1)P4B",
-        "BJ6)S6X",
-        "TFP)4DX",
-        "1KD)XK4",
-        "DGF)FV8",
-        "8Y3)Z9H",
-        "D47)2RN",
-        "H7H)JP7",
-        "NYL)J52",
-        "8YV)FPZ",
-        "268)ZLV",
-        "4PP)BQP",
-        "B2G)5KC",
-        "H1T)T8H",
-        "1X7)BSV",
-        "BSV)QDG",
-        "23Y)XHN",
-        "2ZH)D8J",
-        "9SS)GS1",
-        "R8R)D4W",
-        "58F)B8K",
-        "L12)868",
-        "M84)751",
-        "S4P)2FJ",
-        "PM7)YPB",
-        "VNH)58H",
-        "SQC)9GG",
-        "XC3)WC9",
-        "JN3)VFD",
-        "ZMG)84D",
-        "VHW)B48",
-        "P5G)NLB",
-        "PG7)XLG",
-        "Q6G)664",
-        "MV7)3BJ",
-        "H9K)RRY",
-        "NWQ)DFD",
-        "COM)857",
-        "FZ8)RVH",
-        "1GS)MV6",
-        "9TL)LS5",
-        "BJG)422",
-        "D41)JQH",
-        "FB2)6NW",
-        "12C)9LV",
-        "X4C)MJJ",
-        "67C)N21",
-        "WNW)LQF",
-        "2RH)49M",
-        "FSJ)82F",
-        "PM2)FT5",
-        "FLY)CXX",
-        "KK9)5JZ",
-        "XLK)YNX",
-        "FV2)PCS",
-        "5S4)BD4",
-        "Y4K)V3M",
-        "5YM)TFZ",
-        "8HS)X4Z",
-        "NFV)YZW",
-        "Q3G)4RJ",
-        "QDG)Z9Q",
-        "3NH)M69",
-        "XKW)ZZD",
-        "JKQ)YR9",
-        "PYK)FQM",
-        "W16)2NG",
-        "RKN)HXV",
-        "KCK)326",
-        "41N)JGY",
-        "9DZ)4RT",
-        "FXQ)T6Q",
-        "Q6P)XYF",
-        "L4K)FTQ",
-        "NKB)VBN",
-        "CMM)RV1",
-        "FR7)5CD",
-        "5Y9)XF3",
-        "Z6N)GZJ",
-        "5JZ)6S5",
-        "4W6)TNS",
-        "276)K1W",
-        "ZDC)Z77",
-        "KDV)2BN",
-        "J74)1NG",
-        "2N5)XGN",
-        "47Z)WJY",
-        "CJQ)DMW",
-        "6YX)HCD",
-        "JWB)151",
-        "1C2)GB4",
-        "S7L)953",
-        "WRH)HXF",
-        "VZ8)SPK",
-        "HYZ)RWR",
-        "5J5)89K",
-        "X8S)QHF",
-        "B1T)1XS",
-        "318)8JN",
-        "Y6G)23G",
-        "F8T)6YL",
-        "FFK)5BH",
-        "7YJ)8P1",
-        "GN7)GSR",
-        "9R4)2WM",
-        "RG8)YSX",
-        "XWM)QB8",
-        "SRJ)ZDP",
-        "PHM)N8T",
-        "VY6)87B",
-        "8TT)Z1P",
-        "GS1)48W",
-        "BWJ)RQJ",
-        "GGW)16D",
-        "HMJ)KS5",
-        "KMX)YGR",
-        "F8X)P32",
-        "SKZ)64G",
-        "1K5)6M7",
-        "QVQ)DQ8",
-        "3KW)96K",
-        "VR8)9FZ",
-        "9X9)1VQ",
-        "1NG)ZX9",
-        "FGN)3M6",
-        "5M9)2P9",
-        "3HH)X3G",
-        "XGN)ZH2",
-        "P22)PZJ",
-        "QWT)DST",
-        "SK8)KQM",
-        "1N6)DXG",
-        "J9S)1FB",
-        "PYW)41H",
-        "FYS)366",
-        "8BY)TFP",
-        "JF8)1KD",
-        "WBF)X4C",
-        "8CG)WYQ",
-        "NQL)5ZB",
-        "FBY)3RX",
-        "YNX)7J5",
-        "LSW)BS7",
-        "TMB)KCK",
-        "ZM7)ZV1",
-        "CF9)7DN",
-        "VTZ)P59",
-        "W92)5L6",
-        "N75)318",
-        "8VS)4G5",
-        "CMX)3QL",
-        "RJM)SXY",
-        "WFR)HN9",
-        "832)5C8",
-        "ZPM)789",
-        "LP8)641",
-        "YR9)GW7",
-        "2TN)7VF",
-        "MHK)GXX",
-        "91Z)PZD",
-        "XYL)TMM",
-        "QP8)WRH",
-        "VH9)45H",
-        "2P9)KBG",
-        "4ND)DYC",
-        "KV6)DFJ",
-        "MB5)MW4",
-        "4TD)BJG",
-        "TDK)KLR",
-        "QMR)NVB",
-        "Z1W)13D",
-        "2ZP)8J7",
-        "QYZ)VW5",
-        "PD2)BZ9",
-        "LP3)PTQ",
-        "JC7)CL1",
-        "RZB)G2J",
-        "B7Z)B65",
-        "L7F)4QG",
-        "69W)C92",
-        "WM4)7W8",
-        "YTC)SAN",
-        "28W)H82",
-        "WHP)W16",
-        "NSF)LTC",
-        "DBG)YYJ",
-        "P9R)3DB",
-        "6GR)FLY",
-        "PHX)JWY",
-        "ZZ3)7BH",
-        "ZVD)Z1G",
-        "HSG)2ST",
-        "2NG)C3J",
-        "DGN)CJK",
-        "KR7)9WD",
-        "8CG)YCF",
-        "KVF)MYJ",
-        "MYQ)RYG",
-        "2HZ)5Y9",
-        "LZH)1PN",
-        "H86)DFL",
-        "C3J)4PY",
-        "72S)F52",
-        "D6B)MV7",
-        "Q8K)PSQ",
-        "YYJ)FCL",
-        "ZXH)Z7N",
-        "ZR9)1VD",
-        "VG7)ZT7",
-        "58H)NFF",
-        "DQP)6WG",
-        "7CW)W3Y",
-        "K8Q)QTZ",
-        "FPZ)QSL",
-        "N7R)L4Y",
-        "MYJ)ZJZ",
-        "6P9)368",
-        "S9Q)J4Y",
-        "6L6)17R",
-        "RLW)3V1",
-        "79B)PJW",
-        "RQ1)VN3",
-        "Z4P)Q1X",
-        "8HS)2N5",
-        "953)RZB",
-        "XFQ)YTZ",
-        "9SK)LWH",
-        "RR7)MG8",
-        "6WG)WXR",
-        "P4F)3KH",
-        "KBY)45J",
-        "JNY)JLH",
-        "YX9)S3G",
-        "1GS)6X8",
-        "6DY)BS1",
-        "JS2)8ZT",
-        "XC5)JMW",
-        "CTJ)87Z",
-        "F92)18J",
-        "7FQ)B58",
-        "GCD)7FQ",
-        "NGF)SDC",
-        "HDZ)5MS",
-        "3RX)2ZZ",
-        "GYG)G6H",
-        "TR8)KHK",
-        "LFP)21J",
-        "VH1)G1W",
-        "QJP)ZZ6",
-        "MV6)T55",
-        "H5S)F8K",
-        "1XS)SKZ",
-        "B2G)QLS",
-        "BG8)B36",
-        "WRM)DTK",
-        "T68)P8Q",
-        "YYJ)HN8",
-        "Y8C)NQ3",
-        "QBX)FR1",
-        "6FP)FBY",
-        "DFD)1HB",
-        "7TQ)QFW",
-        "R8T)B4N",
-        "PFT)BC3",
-        "J4Y)ZCV",
-        "3KT)FSJ",
-        "N8T)GT3",
-        "48Z)1S5",
-        "RVX)JVF",
-        "G54)8Z3",
-        "4SD)M6V",
-        "N9R)C8M",
-        "9NH)F8T",
-        "NRB)DK1",
-        "V2T)1MF",
-        "M14)SBV",
-        "H9K)F51",
-        "9RW)71Y",
-        "Z17)VYK",
-        "7BH)GBM",
-        "7B2)8R3",
-        "BW3)WNW",
-        "2D4)9NR",
-        "6NW)69W",
-        "1S5)L6L",
-        "YC8)7YZ",
-        "72T)M1J",
-        "45H)N84",
-        "BGB)6GR",
-        "N75)QD6",
-        "L81)F4V",
-        "CJQ)S6T",
-        "ZP9)NWQ",
-        "RFG)RN3",
-        "YPP)9DZ",
-        "4G3)6SF",
-        "BYX)W1V",
-        "MRR)4QV",
-        "KBB)DPC",
-        "TNP)JJX",
-        "SYT)3FP",
-        "PZD)DZJ",
-        "P32)FFQ",
-        "XKW)K99",
-        "YGJ)63R",
-        "P8Q)Y3P",
-        "LN4)9CB",
-        "ZV1)WRM",
-        "KDB)B1T",
-        "664)4HK",
-        "2HH)9PB",
-        "MLK)LMH",
-        "7J4)1S4",
-        "RLD)1C2",
-        "B8K)8LH",
-        "H7F)4JN",
-        "89K)DQR",
-        "14W)48X",
-        "XF3)MQ6",
-        "T68)XPF",
-        "4LM)YNY",
-        "YGJ)8NZ",
-        "X4M)YTS",
-        "NR6)61R",
-        "9YZ)GDS",
-        "19J)XKK",
-        "8LH)1K2",
-        "DJ2)D3R",
-        "1WJ)V38",
-        "JKQ)YWZ",
-        "W59)N31",
-        "7ZC)W72",
-        "6F2)R9Z",
-        "K9L)F7J",
-        "94B)B1W",
-        "L8T)FDY",
-        "9XQ)2HD",
-        "QZY)5KQ",
-        "NXS)7LK",
-        "WZZ)SYL",
-        "1MF)53Z",
-        "DKL)D27",
-        "NYY)B2G",
-        "KTS)T5V",
-        "NMC)5S4",
-        "Y3B)5FH",
-        "TMM)QDC",
-        "ST3)DQP",
-        "V8J)WDK",
-        "WYQ)8JD",
-        "4QV)74S",
-        "MP4)36G",
-        "P59)4SD",
-        "G47)7GR",
-        "368)M55",
-        "HXV)KK9",
-        "WHW)MVK",
-        "RXB)WVM",
-        "X1S)8NC",
-        "XLZ)DSS",
-        "RL9)C6S",
-        "RMY)C7R",
-        "SHD)VHZ",
-        "7VF)K4Z",
-        "C6T)YKB",
-        "Z1V)84J",
-        "DRX)HPT",
-        "F8H)M45",
-        "6DY)1K3",
-        "V5P)N3F",
-        "6L2)64J",
-        "TYS)JF1",
-        "CF2)PHM",
-        "F4V)RL1",
-        "Y5G)LQK",
-        "5KK)1J2",
-        "7J4)H4T",
-        "YGR)WHW",
-        "KVL)H9C",
-        "FGF)TVG",
-        "YKL)TGN",
-        "L5Q)M84",
-        "NVB)P14",
-        "1S4)FMR",
-        "Q7G)Y5G",
-        "6SM)ZZ3",
-        "JSN)8NM",
-        "R55)XFQ",
-        "N66)S87",
-        "49R)KX9",
-        "YY2)9R4",
-        "YT3)XC3",
-        "MX2)X7F",
-        "K99)VML",
-        "GJL)JF8",
-        "2Q7)23Y",
-        "PH2)PQ3",
-        "87B)GFQ",
-        "WK6)BV2",
-        "3ZX)XM4",
-        "KBG)ZTT",
-        "CXX)RXB",
-        "4JN)LPZ",
-        "K8Q)YRX",
-        "WXS)27J",
-        "4NV)C6T",
-        "471)9YZ",
-        "99G)ZPM",
-        "TV3)HC1",
-        "PXR)V9H",
-        "1YL)WBF",
-        "SGJ)2HG",
-        "W1F)8TT",
-        "BTJ)5Y1",
-        "L68)P69",
-        "857)NFK",
-        "G33)4B4",
-        "L28)WM4",
-        "L75)VHW",
-        "FQQ)VTT",
-        "X4C)FR7",
-        "Q6H)RL9",
-        "TMB)6D1",
-        "WGZ)QJ5",
-        "181)PCT",
-        "53M)6CR",
-        "Y2X)FXQ",
-        "LXJ)73C",
-        "4LJ)HSG",
-        "QHN)LN4",
-        "4S3)BWJ",
-        "9PJ)5RV",
-        "132)H3L",
-        "8BP)QX1",
-        "6L2)TK1",
-        "TGN)7TQ",
-        "ZM7)VXH",
-        "CL1)RLW",
-        "RSZ)G2B",
-        "B4N)BFG",
-        "M1C)Q7R",
-        "8J6)GVY",
-        "MXJ)NWX",
-        "H82)L81",
-        "1VD)4N7",
-        "Y3B)Y7S",
-        "TPD)YOU",
-        "CNP)DJ2",
-        "RXZ)W3F",
-        "9PT)72S",
-        "YHN)LP8",
-        "B48)QZY",
-        "ZZ6)24N",
-        "CVK)WZZ",
-        "14L)FLZ",
-        "MW4)C25",
-        "PZJ)LP3",
-        "7S7)VHL",
-        "9NR)SRR",
-        "1PN)28W",
-        "PBH)S35",
-        "24K)M14",
-        "62S)MP8",
-        "ZTL)6P3",
-        "BC3)6SM",
-        "MSN)WK6",
-        "J7S)CMM",
-        "2RN)N8F",
-        "SZW)WLN",
-        "MDM)VTZ",
-        "2R1)99M",
-        "8W2)D34",
-        "2FJ)SX2",
-        "P69)RHM",
-        "5D2)FDP",
-        "1N2)FGF",
-        "2CD)N47",
-        "PTB)CWG",
-        "S98)8F8",
-        "J3R)LSW",
-        "GHQ)8D5",
-        "DFJ)V99",
-        "M45)L4K",
-        "Q6W)2W5",
-        "261)8VP",
-        "59M)KFQ",
-        "RQJ)1KC",
-        "RHM)FQQ",
-        "SYM)9CQ",
-        "N2Y)YJ8",
-        "F4N)G47",
-        "MZK)XC5",
-        "QDC)WGZ",
-        "PCS)MDL",
-        "F6G)D3G",
-        "HH8)PM5",
-        "WVM)5NF",
-        "MZJ)F6G",
-        "CG1)4GQ",
-        "N57)Z24",
-        "JQL)V1L",
-        "Z9Q)BJ5",
-        "HN9)CTJ",
-        "LQK)844",
-        "BM6)2ZG",
-        "H4N)NT4",
-        "DPJ)B2J",
-        "9BY)H1T",
-        "41W)NWL",
-        "W1V)WJ8",
-        "3QL)K7T",
-        "1HN)12H",
-        "5ZB)443",
-        "Q1X)GN7",
-        "K1H)VY6",
-        "FMR)WWD",
-        "ZJZ)5D2",
-        "3V1)YQ6",
-        "QLS)LJR",
-        "V6V)N57",
-        "NJP)G33",
-        "GGP)7WY",
-        "GYV)6DT",
-        "5R7)M2B",
-        "N6S)YB3",
-        "DTK)SHR",
-        "PQ3)QP8",
-        "7WS)CFQ",
-        "XSY)BXP",
-        "DMT)YBR",
-        "ZVP)L4F",
-        "B36)TYS",
-        "S4X)3P9",
-        "B2J)WXS",
-        "2ST)LJS",
-        "MB5)V7C",
-        "FV8)KBY",
-        "L75)DQ1",
-        "VPD)DKL",
-        "W72)WH1",
-        "MR7)ST3",
-        "2HG)ZYX",
-        "N21)MXK",
-        "VNB)FB2",
-        "PTQ)QCH",
-        "64D)6L6",
-        "DZJ)SRV",
-        "17V)JZF",
-        "K6X)LFP",
-        "8R3)5LN",
-        "YRD)RS5",
-        "Q3L)2WC",
-        "S8J)BG8",
-        "2ZG)W3S",
-        "8F8)DYF",
-        "WH1)Y9P",
-        "2XP)NMC",
-        "48W)JKK",
-        "49R)N3Y",
-        "98X)F4N",
-        "72S)4PP",
-        "64T)CW9",
-        "TSL)SFW",
-        "YH5)2RH",
-        "2DW)1RW",
-        "BNH)N2Y",
-        "P62)461",
-        "DPC)QXZ",
-        "M3R)VW3",
-        "4P6)CRC",
-        "835)S7L",
-        "M14)RB1",
-        "FQM)PD2",
-        "3KH)8Y3",
-        "SQC)69C",
-        "G8D)J74",
-        "3DC)2R1",
-        "2B6)9PJ",
-        "Y9P)132",
-        "9DQ)59F",
-        "19C)WRW",
-        "GYG)GGP",
-        "DY3)V6V",
-        "HLF)2YD",
-        "KM8)XSJ",
-        "96K)KQZ",
-        "LQ6)CF9",
-        "YPB)NBK",
-        "N46)HBH",
-        "YH6)3PZ",
-        "2WC)YNV",
-        "L1S)TNP",
-        "5ZH)49R",
-        "T8G)JRG",
-        "ZZD)7CW",
-        "1B8)NBX",
-        "H22)H7H",
-        "SL5)NBL",
-        "Y9P)471",
-        "2DQ)25P",
-        "9XH)NYY",
-        "4BP)3KT",
-        "QQC)D95",
-        "VXH)RV3",
-        "LWH)1B8",
-        "2QG)91Z",
-        "H9C)BTJ",
-        "YRX)ZTL",
-        "15N)74D",
-        "FGN)KM8",
-        "M8W)2HR",
-        "BV2)BHJ",
-        "S6H)KVL",
-        "7W8)NKB",
-        "D8K)DBG",
-        "9RL)R8R",
-        "G2B)88J",
-        "RJY)99G",
-        "4GQ)JGG",
-        "24N)KVY",
-        "8HH)19C",
-        "PVZ)C18",
-        "T6Q)NKW",
-        "8VS)9XH",
-        "ZT7)MP4",
-        "3P9)GYG",
-        "M4D)D1C",
-        "Y7S)QMR",
-        "5NF)TRL",
-        "8D5)P22",
-        "PGP)ZXH",
-        "7YZ)YM8",
-        "LZ3)77W",
-        "PKN)PBH",
-        "DT2)LML",
-        "RJ9)YNZ",
-        "C8V)H6X",
-        "BX2)FZL",
-        "4RJ)VRV",
-        "RF6)C2Q",
-        "FF7)JQW",
-        "WLN)9GJ",
-        "Z34)BTY",
-        "CNT)TM5",
-        "LC2)WLL",
-        "7DJ)Q3R",
-        "12H)HSB",
-        "1PN)1HY",
-        "4BF)BXF",
-        "J93)LP1",
-        "19J)J6C",
-        "YSX)NSF",
-        "3MY)PFT",
-        "1TN)W59",
-        "WL8)B7Z",
-        "NBT)PBT",
-        "LQ6)FJ4",
-        "J84)Q8P",
-        "31T)DF6",
-        "9PW)7S7",
-        "D3R)DPJ",
-        "NBK)RX6",
-        "QSL)34T",
-        "HYB)GSY",
-        "GBM)PKS",
-        "SP2)TPL",
-        "DY9)KPK",
-        "21J)CYG",
-        "P69)NQL",
-        "FMT)621",
-        "MXK)RSZ",
-        "2CJ)WTS",
-        "F2S)3HH",
-        "PFC)2ZP",
-        "T41)62S",
-        "TSS)5YM",
-        "D95)QQK",
-        "XK4)23T",
-        "6ZX)K63",
-        "3BY)NJZ",
-        "XD8)PGD",
-        "8NC)3WK",
-        "B1V)W1Y",
-        "ZQW)13Y",
-        "W2L)62B",
-        "PQ2)Y4K",
-        "C25)FDX",
-        "386)D41",
-        "SKX)XSY",
-        "QW6)2DQ",
-        "FBY)V8J",
-        "RS5)PRC",
-        "DQ8)KDB",
-        "6S5)PXR",
-        "TVG)9RL",
-        "G7Q)92C",
-        "T88)L16",
-        "BFG)RY2",
-        "NFK)6LG",
-        "RB1)Y74",
-        "H21)24K",
-        "YNF)63K",
-        "8D1)X4D",
-        "FS2)12C",
-        "RQF)K7B",
-        "MTZ)JRP",
-        "3WK)L8T",
-        "2ZP)P1S",
-        "L5F)XQD",
-        "B4N)3NK",
-        "DTT)VGM",
-        "YKP)XKW",
-        "PZX)TNX",
-        "FT6)7ZC",
-        "ZBT)L4Z",
-        "27N)RJ9",
-        "GXX)3NH",
-        "WCF)1GS",
-        "7C9)PFJ",
-        "DQR)28X",
-        "Q8Z)K6J",
-        "P4B)Z4P",
-        "WDK)P5G",
-        "1FB)VH9",
-        "SD4)48Z",
-        "RZS)FWM",
-        "T3D)2QG",
-        "VZ8)HM6",
-        "BXP)ZMG",
-        "69C)TPD",
-        "WJY)4KG",
-        "QX1)YHB",
-        "SJD)J9S",
-        "WGD)QHH",
-        "RB3)6W5",
-        "G9P)V58",
-        "K6J)XD8",
-        "TNS)CD1",
-        "Y8Z)HDZ",
-        "C13)RJM",
-        "QCN)14L",
-        "DDY)Q6P",
-        "JWY)B59",
-        "423)PMR",
-        "7M6)H9K",
-        "64J)Z34",
-        "MRF)33G",
-        "G1W)94B",
-        "KJV)LGJ",
-        "LPZ)5RR",
-        "S6T)DGF",
-        "B45)B3H",
-        "YQ6)82C",
-        "F7J)C8V",
-        "KXY)ZWW",
-        "TCL)WHP",
-        "HQV)DLT",
-        "J13)X1S",
-        "QQK)6L2",
-        "HBQ)NFV",
-        "NKW)J84",
-        "X4D)DTT",
-        "5CD)47Z",
-        "4TD)D47",
-        "HHW)XQJ",
-        "3BY)N46",
-        "8BF)2PM",
-        "VBN)XRW",
-        "6P3)P74",
-        "WK5)1X6",
-        "MLZ)RZS",
-        "SYL)K6X",
-        "D1C)T8G",
-        "BL4)GYV",
-        "LFP)Q6W",
-        "FDY)JN4",
-        "3BJ)V2T",
-        "4QG)FFT",
-        "YZW)VC1",
-        "NT4)5KR",
-        "FFQ)NXS",
-        "5RV)9NH",
-        "SFW)TV3",
-        "1HB)848",
-        "6D1)L8F",
-        "9GG)ZVP",
-        "M86)LV1",
-        "KVY)JCV",
-        "HT5)M86",
-        "8Y3)27N",
-        "4RT)ZZ8",
-        "DXC)RMY",
-        "6XK)J88",
-        "D1Y)M3Z",
-        "HXF)D1Y",
-        "789)423",
-        "6DT)12V",
-        "J52)J7S",
-        "WD9)VR8",
-        "BJ5)9SS",
-        "S87)MLK",
-        "XY4)HYZ",
-        "YHB)S5P",
-        "6X8)S4X",
-        "RWR)LHC",
-        "NDK)2G9",
-        "5KQ)PYK",
-        "YRD)L1S",
-        "1K3)Q3G",
-        "VW3)3BY",
-        "BFG)CYM",
-        "SH8)8HH",
-        "SHR)2HZ",
-        "LGJ)FT6",
-        "34T)1H4",
-        "JC7)VH1",
-        "FT5)T8W",
-        "CC3)TQS",
-        "GKJ)BN8",
-        "FFT)KFY",
-        "19R)Y6G",
-        "V7C)S6H",
-        "C18)M1C",
-        "Z8C)W3N",
-        "VYK)3JS",
-        "82C)H86",
-        "M3R)H22",
-        "NSX)8BY",
-        "JLH)F24",
-        "SNM)MTH",
-        "B1W)WK5",
-        "WH1)N7R",
-        "8ZT)BNH",
-        "SDC)Q5S",
-        "M2B)43W",
-        "Z5Z)SK8",
-        "TM5)75H",
-        "J74)JQL",
-        "N31)7YJ",
-        "GJJ)QJP",
-        "6YR)8CG",
-        "8P1)6ZX",
-        "XFQ)SYM",
-        "K6X)5ZH",
-        "BQP)GM5",
-        "ZBH)RF6",
-        "R1Q)SQC",
-        "96D)TMB",
-        "6LG)C13",
-        "HN8)SZW",
-        "NQS)15N",
-        "5BH)TSS",
-        "XHN)6YR",
-        "4G5)M8W",
-        "NLB)SRM",
-        "TSL)TDK",
-        "9FZ)XLK",
-        "MG8)B21",
-        "5V4)QYW",
-        "PCT)1K5",
-        "KFQ)KJV",
-        "W76)CS4",
-        "N3F)T3D",
-        "BS7)VNB",
-        "JCV)RKN",
-        "B38)J93",
-        "ZK7)V5P",
-        "Z3T)1N2",
-        "DB7)SL5",
-        "4VR)CJQ",
-        "D3G)KHS",
-        "X8S)CRT",
-        "7J5)YKP",
-        "W3Y)HMJ",
-        "JQH)DW9",
-        "6YL)1JX",
-        "WPN)FGN",
-        "1JX)W31",
-        "39W)L7F",
-        "HCD)X36",
-        "WLL)KFD",
-        "HD3)GGW",
-        "NTX)Z1W",
-        "13D)3X8",
-        "GDS)M3R",
-        "BSR)NQS",
-        "Z1G)FZX",
-        "7C2)Z1V",
-        "WTS)SRJ",
-        "YB3)H3V",
-        "SRV)MFY",
-        "D34)JK9",
-        "XQJ)924",
-        "SWK)ZBT",
-        "S4Q)NDK",
-        "LML)Y2X",
-        "JS2)MX2",
-        "JRP)2VG",
-        "KS5)RLD",
-        "KHS)D2Q",
-        "C6S)1TN",
-        "23G)P33",
-        "5KC)WPN",
-        "VC1)4WW",
-        "25P)J3R",
-        "G22)2CD",
-        "Z24)BL4",
-        "ZXC)WRY",
-        "443)S9Q",
-        "CYM)C5K",
-        "H6X)VKY",
-        "N3Y)BM6",
-        "D4R)4VR",
-        "DYF)K1H",
-        "45J)MHK",
-        "Z1P)7WS",
-        "XPF)CV9",
-        "2HT)WD9",
-        "QCH)PYW",
-        "N84)QKS",
-        "1RW)2TN",
-        "NRB)FSZ",
-        "PQV)78X",
-        "844)NNW",
-        "W6S)Q3L",
-        "GM5)6LY",
-        "XKK)GJL",
-        "XRH)832",
-        "V3M)X17",
-        "TFZ)T78",
-        "XLZ)6F2",
-        "QM9)GKJ",
-        "MTH)H19",
-        "XGB)79B",
-        "SK8)MB5",
-        "NR4)Y7Q",
-        "GFQ)ZXC",
-        "89C)M42",
-        "DW9)BT3",
-        "M84)SH8",
-        "75H)P9R",
-        "ZYX)Q6G",
-        "DHZ)T83",
-        "NVM)Y3B",
-        "7JS)SRW",
-        "GB3)7C9",
-        "6LY)MRR",
-        "S3G)18Z",
-        "C4M)BYX",
-        "23T)9BV",
-        "5L6)CMX",
-        "MV6)PG7",
-        "DFD)SNK",
-        "7BH)BKZ",
-        "YY2)LMJ",
-        "TRH)5M9",
-        "47Z)4BF",
-        "WWD)KB3",
-    ];
-    input
-}
-
-fn test_input() -> Vec<&'static str> {
-    vec![
-        "COM)B",
-        "B)C",
-        "C)D",
-        "D)E",
-        "E)F",
-        "B)G",
-        "G)H",
-        "D)I",
-        "E)J",
-        "J)K",
-        "K)L",
-    ]
-}
-
-fn test_input2() -> Vec<&'static str> {
-    vec![
-        "COM)B",
-        "B)C",
-        "C)D",
-        "D)E",
-        "E)F",
-        "B)G",
-        "G)H",
-        "D)I",
-        "E)J",
-        "J)K",
-        "K)L",
-        "K)YOU",
-        "I)SAN",
-    ]
-}
-
-#[test]
-fn test_part1() {
-    let test_input = test_input();
-    assert_eq!(part1(&test_input), 42);
-}
-
-#[test]
-fn test_part2() {
-    let test_input = test_input2();
-    let orbits = parse_orbits(&test_input);
-    let graph = build_orbit_graph(orbits);
-    let shortest_path = find_shortest_path_length(&graph, String::from("YOU"), String::from("SAN"));
-    assert_eq!(shortest_path, 4);
+    lib::solution::run_day(6, &Day06);
 }
@@ -0,0 +1,127 @@
+use lib::solution::Solution;
+use std::ops::Range;
+use std::fmt::{Display, Formatter, Error};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<String, String> {
+        let pixels = input.trim();
+        if !pixels.chars().all(|pixel| pixel.is_ascii_digit()) {
+            return Err(String::from("Pixels must be digits"));
+        }
+        if pixels.is_empty() || !pixels.len().is_multiple_of(25 * 6) {
+            return Err(format!("{} pixels do not make whole 25x6 layers", pixels.len()));
+        }
+        Ok(pixels.to_string())
+    }
+
+    fn part1(&self, input: &String) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(&self, input: &String) -> String {
+        part2(input.clone())
+    }
+}
+
+fn part1(input: String) -> usize {
+    let image = Image::from_string(input, 25, 6);
+
+    let (idx, _n_zeroes) = image.layers.iter()
+        .enumerate()
+        .map(|(idx, layer)| {
+            (idx, layer.pixels.iter().filter(|pixel| (**pixel) == 0_usize).collect::<Vec<_>>().len())
+        })
+        .min_by(|left, right| left.1.cmp(&right.1))
+        .unwrap();
+
+    let layer = image.layers.get(idx).unwrap();
+    layer.count_digits(2) * layer.count_digits(1)
+}
+
+
+fn part2(input: String) -> String {
+    let image = Image::from_string(input, 25, 6);
+    image.to_string()
+}
+
+struct Image {
+    layers: Vec<Layer>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug)]
+struct Layer {
+    pixels: Vec<usize>
+}
+
+impl Layer {
+    fn count_digits(&self, digit: usize) -> usize {
+        self.pixels.iter().filter(|pixel| **pixel == digit).count()
+    }
+}
+
+impl Image {
+    fn from_string(raw_pixels: String, width: usize, height: usize) -> Self {
+        let layer_size = height * width;
+        let n_windows = raw_pixels.len() / layer_size;
+
+        let layers = (0..n_windows).map(|idx| {
+            let range = Range { start: idx * layer_size, end: (idx + 1) * layer_size };
+            let pixels_for_layer = raw_pixels[range]
+                .chars()
+                .take(layer_size)
+                .map(|x| x.to_digit(10).unwrap() as usize)
+                .collect::<Vec<usize>>();
+            Layer { pixels: pixels_for_layer }
+        }).collect::<Vec<Layer>>();
+
+        Image { width, height, layers }
+    }
+
+    fn stack_layers(&self) -> Vec<usize> {
+        let mut final_image = (0..self.width * self.height)
+            .map(|_| 2)
+            .collect::<Vec<_>>();
+
+
+        final_image.iter_mut().enumerate().for_each(|(idx, pixel)| {
+            for layer in &self.layers {
+                let layer_pixel = layer.pixels.get(idx).unwrap();
+                if *pixel == 2_usize {
+                    *pixel = *layer_pixel;
+                }
+            }
+        });
+
+        final_image
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut output = String::from("");
+        let stacked_image = self.stack_layers();
+
+        for (idx, pixel) in stacked_image.iter().enumerate() {
+            if idx % self.width == 0 && idx > 0 {
+                output.push('\n');
+            }
+            let next = match pixel {
+                1 => "x",
+                _ => " "
+            };
+            output.push_str(next);
+            output.push(' ');
+        }
+
+        write!(f, "\n{}", output)
+    }
+}
+
@@ -0,0 +1,34 @@
+use intcode::{IntCodeComputer, Memory, MemoryValue};
+use lib::solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Memory;
+    type Answer1 = MemoryValue;
+    type Answer2 = MemoryValue;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> MemoryValue {
+        part1(program.clone())
+    }
+
+    fn part2(&self, program: &Memory) -> MemoryValue {
+        part2(program.clone())
+    }
+}
+
+fn part1(input: Memory) -> MemoryValue {
+    let mut cpu = IntCodeComputer::new(input);
+    cpu.run(vec![1]);
+    *cpu.get_output().first().unwrap()
+}
+
+fn part2(input: Memory) -> MemoryValue {
+    let mut cpu = IntCodeComputer::new(input);
+    cpu.run(vec![2]);
+    *cpu.get_output().first().unwrap()
+}
@@ -0,0 +1,402 @@
+use std::collections::{HashSet, HashMap};
+use itertools::Itertools;
+use lib::solution::Solution;
+use std::cmp::Ordering;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(&self, input: &str) -> Result<String, String> {
+        if let Some(invalid) = input.chars().find(|c| !"#.".contains(*c) && !c.is_whitespace()) {
+            return Err(format!("Unexpected '{}' in the asteroid map", invalid));
+        }
+        // Part 2 asks for the 200th asteroid vaporized from the station
+        let n_asteroids = input.matches('#').count();
+        if n_asteroids <= 200 {
+            return Err(format!("The map shows {} asteroids, part 2 needs more than 200", n_asteroids));
+        }
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &String) -> usize {
+        let (n_asteroids, _location) = part1(input);
+        n_asteroids
+    }
+
+    fn part2(&self, input: &String) -> i32 {
+        let (_, station) = part1(input);
+        let location = part2(input, &station);
+        location.x * 100 + location.y
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Location {
+    x: i32,
+    y: i32,
+}
+
+impl Location {
+    fn distance(&self, other: &Location) -> Vector {
+        Vector {
+            x: other.x - self.x,
+            y: other.y - self.y,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+struct Vector {
+    x: i32,
+    y: i32,
+}
+
+impl Ord for Vector {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.angle().cmp(&other.angle()) {
+            Ordering::Equal => self.len_as_i32().cmp(&other.len_as_i32()),
+            any => any
+        }
+    }
+}
+
+impl PartialOrd for Vector {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Vector {
+    fn normalize(&self) -> Vector {
+        if self.x == 0 && self.y != 0 {
+            Vector { x: 0, y: self.y / self.y.abs() }
+        } else if self.x != 0 && self.y == 0 {
+            Vector { x: self.x / self.x.abs(), y: 0 }
+        } else if self.x != 0 && self.y != 0 {
+            let gcd = num_integer::gcd(self.x, self.y);
+            Vector { x: self.x / gcd, y: self.y / gcd }
+        } else {
+            *self
+        }
+    }
+
+    /// The clockwise angle from straight up in millionths of a degree, where y grows downwards.
+    fn angle(&self) -> i64 {
+        let mut degrees = (self.x as f64).atan2(-self.y as f64).to_degrees();
+
+        if degrees < 0.0 {
+            degrees += 360.0;
+        }
+
+        (degrees * 1_000_000_f64).round() as i64
+    }
+
+    fn len(&self) -> f32 {
+        ((self.x * self.x + self.y * self.y) as f32).sqrt()
+    }
+
+    fn len_as_i32(&self) -> i32 {
+        (self.len() * 100.0) as i32
+    }
+}
+
+fn part1(input: &str) -> (usize, Location) {
+    let mut locations: Vec<Location> = parse_locations(input);
+    lib::debug!("Found {:?} locations", locations.len());
+    find_best_location(&mut locations)
+}
+
+fn part2(input: &str, starting_location: &Location) -> Location {
+    vaporization_order(input, starting_location)[199]
+}
+
+/// The asteroids in the order the laser vaporizes them, turning clockwise from straight up and
+/// hitting only the nearest asteroid in each direction per rotation.
+fn vaporization_order(input: &str, starting_location: &Location) -> Vec<Location> {
+    // Group the other asteroids by their exact direction, nearest first
+    let mut vector_map: DirectionVectorMap = parse_locations(input)
+        .iter()
+        .filter(|l| starting_location.ne(*l))
+        .map(|l| starting_location.distance(l))
+        .sorted()
+        .map(|v| (v.normalize(), v))
+        .into_group_map();
+
+    let directions = vector_map.keys()
+        .sorted_by_key(|direction| direction.angle())
+        .cloned()
+        .collect::<Vec<Vector>>();
+
+    let mut order = vec![];
+    while !vector_map.is_empty() {
+        for direction in &directions {
+            if let Some(vectors) = vector_map.get_mut(direction) {
+                let vector = vectors.remove(0);
+                order.push(Location {
+                    x: starting_location.x + vector.x,
+                    y: starting_location.y + vector.y,
+                });
+                if vectors.is_empty() {
+                    vector_map.remove(direction);
+                }
+            }
+        }
+    }
+
+    order
+}
+
+type DirectionVectorMap = HashMap<Vector, Vec<Vector>>;
+
+fn find_best_location(locations: &mut [Location]) -> (usize, Location) {
+    locations.iter()
+        .map(|l| {
+            let n_line_of_sight = find_los_asteroids(locations, l);
+            (n_line_of_sight, *l)
+        })
+        .max_by_key(|(n, _)| *n)
+        .expect("No locations found")
+}
+
+fn find_asteroids(locations: &[Location], loc: &Location) -> HashSet<Vector> {
+    locations.iter()
+        .filter(|other| loc.ne(other))
+        .map(|other| loc.distance(other).normalize())
+        .collect::<HashSet<Vector>>()
+}
+
+fn find_los_asteroids(locations: &[Location], loc: &Location) -> usize {
+    let unique_vectors = find_asteroids(locations, loc);
+    unique_vectors.len()
+}
+
+fn parse_locations(input: &str) -> Vec<Location> {
+    input.lines()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.trim().chars()
+                .enumerate()
+                .filter(|(_, char)| *char == '#')
+                .map(|(column, _)| {
+                    Location { x: column as i32, y: row as i32 }
+                })
+                .collect::<Vec<Location>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Vector, parse_locations, Location, part1, part2, vaporization_order, find_asteroids, find_los_asteroids};
+
+    #[test]
+    fn test_normalize_vector() {
+        assert_eq!(Vector { x: 2, y: 2 }.normalize(), Vector { x: 1, y: 1 });
+        assert_eq!(Vector { x: -2, y: 2 }.normalize(), Vector { x: -1, y: 1 });
+        assert_eq!(Vector { x: 2, y: -2 }.normalize(), Vector { x: 1, y: -1 });
+        assert_eq!(Vector { x: -10, y: -5 }.normalize(), Vector { x: -2, y: -1 });
+        assert_eq!(Vector { x: -5, y: -10 }.normalize(), Vector { x: -1, y: -2 });
+        assert_eq!(Vector { x: 7, y: -2 }.normalize(), Vector { x: 7, y: -2 });
+        assert_eq!(Vector { x: -7, y: -2 }.normalize(), Vector { x: -7, y: -2 });
+        assert_eq!(Vector { x: -6, y: -10 }.normalize(), Vector { x: -3, y: -5 });
+        assert_eq!(Vector { x: -4, y: 0 }.normalize(), Vector { x: -1, y: 0 });
+    }
+
+    #[test]
+    fn test_angle() {
+        assert_eq!(Vector { x: 0, y: -3 }.angle(), 0);
+        assert_eq!(Vector { x: 2, y: 2 }.angle(), 135_000_000);
+        assert_eq!(Vector { x: 3, y: 1 }.angle(), 108_434_949);
+        assert_eq!(Vector { x: -2, y: 9 }.angle(), 192_528_808);
+        assert_eq!(Vector { x: -1, y: -1 }.angle(), 315_000_000);
+    }
+
+    #[test]
+    fn test_part1_small() {
+        let input: &'static str = " ......#.#.
+                                    #..#.#....
+                                    ..#######.
+                                    .#.#.###..
+                                    .#..#.....
+                                    ..#....#.#
+                                    #..#....#.
+                                    .##.#..###
+                                    ##...#..#.
+                                    .#....####";
+
+        let best_location = Location { x: 5, y: 8 };
+
+        let locations = parse_locations(input);
+        let unique_vectors = find_asteroids(&locations, &best_location);
+        let (n_asteroids, found_location) = part1(input);
+
+        assert_eq!(unique_vectors.len(), 33);
+        assert_eq!(n_asteroids, 33);
+        assert_eq!(best_location, found_location);
+    }
+
+    #[test]
+    fn test_part1_small_2() {
+        let input: &'static str = "\
+            #.#...#.#.
+            .###....#.
+            .#....#...
+            ##.#.#.#.#
+            ....#.#.#.
+            .##..###.#
+            ..#...##..
+            ..##....##
+            ......#...
+            .####.###.";
+
+        let best_location = Location { x: 1, y: 2 };
+
+        let locations = parse_locations(input);
+        let unique_vectors = find_asteroids(&locations, &best_location);
+        let (n_asteroids, found_location) = part1(input);
+
+        assert_eq!(unique_vectors.len(), 35);
+        assert_eq!(n_asteroids, 35);
+        assert_eq!(best_location, found_location);
+    }
+
+    #[test]
+    fn test_part1_small_3() {
+        let input: &'static str = "\
+            .#..#..###
+            ####.###.#
+            ....###.#.
+            ..###.##.#
+            ##.##.#.#.
+            ....###..#
+            ..#.#..#.#
+            #..#.#.###
+            .##...##.#
+            .....#.#..";
+
+        let best_location = Location { x: 6, y: 3 };
+
+        let locations = parse_locations(input);
+        let unique_vectors = find_asteroids(&locations, &best_location);
+        let (n_asteroids, found_location) = part1(input);
+
+        assert_eq!(unique_vectors.len(), 41);
+        assert_eq!(n_asteroids, 41);
+        assert_eq!(best_location, found_location);
+    }
+
+    #[test]
+    fn test_part1_medium() {
+        let input: &'static str = "\
+            .#..##.###...#######
+            ##.############..##.
+            .#.######.########.#
+            .###.#######.####.#.
+            #####.##.#.##.###.##
+            ..#####..#.#########
+            ####################
+            #.####....###.#.#.##
+            ##.#################
+            #####.##.###..####..
+            ..######..##.#######
+            ####.##.####...##..#
+            .#####..#.######.###
+            ##...#.##########...
+            #.##########.#######
+            .####.#.###.###.#.##
+            ....##.##.###..#####
+            .#.#.###########.###
+            #.#.#.#####.####.###
+            ###.##.####.##.#..##";
+
+
+        let best_location = Location { x: 11, y: 13 };
+
+        let locations = parse_locations(input);
+        let unique_vectors = find_asteroids(&locations, &best_location);
+        let (n_asteroids, found_location) = part1(input);
+
+        assert_eq!(unique_vectors.len(), 210);
+        assert_eq!(n_asteroids, 210);
+        assert_eq!(best_location, found_location);
+    }
+
+    #[test]
+    fn test_part2_medium() {
+        let input: &'static str = "\
+            .#..##.###...#######
+            ##.############..##.
+            .#.######.########.#
+            .###.#######.####.#.
+            #####.##.#.##.###.##
+            ..#####..#.#########
+            ####################
+            #.####....###.#.#.##
+            ##.#################
+            #####.##.###..####..
+            ..######..##.#######
+            ####.##.####...##..#
+            .#####..#.######.###
+            ##...#.##########...
+            #.##########.#######
+            .####.#.###.###.#.##
+            ....##.##.###..#####
+            .#.#.###########.###
+            #.#.#.#####.####.###
+            ###.##.####.##.#..##";
+
+
+        let station = Location { x: 11, y: 13 };
+
+        let order = vaporization_order(input, &station);
+        assert_eq!(order.len(), 299);
+        assert_eq!(order[0], Location { x: 11, y: 12 });
+        assert_eq!(order[1], Location { x: 12, y: 1 });
+        assert_eq!(order[9], Location { x: 12, y: 8 });
+        assert_eq!(order[49], Location { x: 16, y: 9 });
+        assert_eq!(order[198], Location { x: 9, y: 6 });
+        assert_eq!(order[200], Location { x: 10, y: 9 });
+        assert_eq!(order[298], Location { x: 11, y: 1 });
+        assert_eq!(part2(input, &station), Location { x: 8, y: 2 });
+    }
+
+    #[test]
+    fn test_location_parsing() {
+        let input: &'static str = " ......#.#.
+                                    #..#.#....
+                                    ..#######.
+                                    .#.#.###..
+                                    .#..#.....
+                                    ..#....#.#
+                                    #..#....#.
+                                    .##.#..###
+                                    ##...#..#.
+                                    .#....####";
+
+        let locations = parse_locations(input);
+        assert_eq!(locations.len(), 40);
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let input: &'static str = " ......#.#.
+                                    #..#.#....
+                                    ..#######.
+                                    .#.#.###..
+                                    .#..#.....
+                                    ..#....#.#
+                                    #..#....#.
+                                    .##.#..###
+                                    ##...#..#.
+                                    .#....####";
+
+        let locations = parse_locations(input);
+        let l = Location { x: 5, y: 8 };
+        let n_asteroids = find_los_asteroids(&locations, &l);
+        assert_eq!(n_asteroids, 33);
+    }
+}
@@ -0,0 +1,144 @@
+use intcode::{IntCodeComputer, IoDevice, Memory, MemoryValue};
+use lib::export::{Colour, Image, Rgb, BLACK, WHITE};
+use lib::geom::{Direction, Point2};
+use lib::solution::Solution;
+use lib::SparseGrid;
+use std::collections::HashSet;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Memory;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> usize {
+        paint_hull(program, Panel::Black).unique_panels.len()
+    }
+
+    fn part2(&self, program: &Memory) -> String {
+        format!("\n{}", paint_hull(program, Panel::White).grid)
+    }
+}
+
+impl Day11 {
+    /// The registration identifier painted by part 2, ten pixels per panel.
+    pub fn hull_image(&self, program: &Memory) -> Image {
+        paint_hull(program, Panel::White)
+            .grid
+            .to_image(BLACK)
+            .scaled(10)
+    }
+}
+
+fn paint_hull(program: &Memory, start: Panel) -> Robot {
+    let mut cpu = IntCodeComputer::new(program.clone());
+    let mut robot = Robot::new();
+    robot.grid.set(0, 0, start);
+    cpu.run_with(&mut robot);
+    robot
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+enum Panel {
+    #[default]
+    Black,
+    White,
+}
+
+impl Display for Panel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Panel::Black => write!(f, "."),
+            Panel::White => write!(f, "#"),
+        }
+    }
+}
+
+impl Colour for Panel {
+    fn colour(&self) -> Rgb {
+        match self {
+            Panel::Black => BLACK,
+            Panel::White => WHITE,
+        }
+    }
+}
+
+impl Panel {
+    fn to_input(self) -> MemoryValue {
+        match self {
+            Panel::Black => 0,
+            Panel::White => 1,
+        }
+    }
+
+    fn from_isize(input: isize) -> Self {
+        match input {
+            0 => Panel::Black,
+            1 => Panel::White,
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct Robot {
+    grid: SparseGrid<Panel>,
+    unique_panels: HashSet<Point2<isize>>,
+    position: Point2<isize>,
+    direction: Direction,
+    color_to_paint: Option<MemoryValue>,
+}
+
+impl Robot {
+    fn new() -> Self {
+        Robot {
+            grid: SparseGrid::new(),
+            unique_panels: HashSet::new(),
+            position: Point2::default(),
+            direction: Direction::North,
+            color_to_paint: None,
+        }
+    }
+
+    fn paint(&mut self, color_to_paint: MemoryValue) {
+        let Point2 { x, y } = self.position;
+        self.grid.set(x, y, Panel::from_isize(color_to_paint));
+        self.unique_panels.insert(self.position);
+    }
+
+    fn turn(&mut self, direction: MemoryValue) {
+        match direction {
+            0 => self.direction = self.direction.turn_left(),
+            1 => self.direction = self.direction.turn_right(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn step(&mut self) {
+        self.position += self.direction;
+    }
+}
+
+impl IoDevice for Robot {
+    fn input(&mut self) -> Option<MemoryValue> {
+        let Point2 { x, y } = self.position;
+        let panel = self.grid.get(x, y).copied().unwrap_or_default();
+        Some(panel.to_input())
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        match self.color_to_paint.take() {
+            None => self.color_to_paint = Some(value),
+            Some(color_to_paint) => {
+                self.paint(color_to_paint);
+                self.turn(value);
+                self.step();
+            }
+        }
+    }
+}
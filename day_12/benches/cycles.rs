@@ -0,0 +1,17 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_12::Day12;
+use lib::solution::Solution;
+
+const INPUT: &str = include_str!("../../fixtures/day12.txt");
+
+fn cycles(c: &mut Criterion) {
+    let system = Day12.parse(INPUT).unwrap();
+    let mut group = c.benchmark_group("day_12");
+    group.bench_function("part 1", |b| b.iter(|| Day12.part1(black_box(&system))));
+    group.sample_size(10);
+    group.bench_function("part 2", |b| b.iter(|| Day12.part2(black_box(&system))));
+    group.finish();
+}
+
+criterion_group!(benches, cycles);
+criterion_main!(benches);
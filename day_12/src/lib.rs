@@ -0,0 +1,256 @@
+use lib::geom::Point3;
+use lib::solution::Solution;
+use std::fmt::{Display, Formatter, Error};
+use std::ops::Add;
+use std::cmp::Ordering;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = OrbitalSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<OrbitalSystem, String> {
+        let moons = lib::input::lines(input)
+            .map(|line| parse_vector(line).map(Satellite::new))
+            .collect::<Result<Vec<Satellite>, String>>()?;
+        if moons.is_empty() {
+            return Err(String::from("Expected at least one moon"));
+        }
+        Ok(OrbitalSystem { moons })
+    }
+
+    fn part1(&self, system: &OrbitalSystem) -> usize {
+        part1(system.clone())
+    }
+
+    fn part2(&self, system: &OrbitalSystem) -> usize {
+        part2(system.clone())
+    }
+}
+
+/// Parses a position like `<x=-1, y=0, z=2>`.
+fn parse_vector(line: &str) -> Result<Vector, String> {
+    let invalid = || format!("Expected a position like '<x=1, y=2, z=3>', found '{}'", line);
+    let coordinates = line.trim_matches(|c| c == '<' || c == '>')
+        .split(", ")
+        .map(|coordinate| coordinate.get(2..).and_then(|value| value.parse().ok()).ok_or_else(invalid))
+        .collect::<Result<Vec<i32>, String>>()?;
+    match coordinates[..] {
+        [x, y, z] => Ok(Vector::new(x, y, z)),
+        _ => Err(invalid()),
+    }
+}
+
+fn part1(system: OrbitalSystem) -> usize {
+    let mut system = system;
+    for _ in 0..1000 {
+        system.tick();
+    }
+    system.total_energy()
+}
+
+fn part2(initial_system: OrbitalSystem) -> usize {
+    let system_x = OrbitalSystem {
+        moons: initial_system.moons.iter().map(|m| {
+            Satellite::new(Vector::new(m.position.x, 0, 0))
+        }).collect()
+    };
+
+    let system_y = OrbitalSystem {
+        moons: initial_system.moons.iter().map(|m| {
+            Satellite::new(Vector::new(0, m.position.y, 0))
+        }).collect()
+    };
+
+    let system_z = OrbitalSystem {
+        moons: initial_system.moons.iter().map(|m| {
+            Satellite::new(Vector::new(0, 0, m.position.z))
+        }).collect()
+    };
+
+    let x_steps = find_steps_for_axis(system_x);
+    let y_steps = find_steps_for_axis(system_y);
+    let z_steps = find_steps_for_axis(system_z);
+
+    // Each axis moves independently, so the whole system repeats once all of them do
+    lcm(lcm(x_steps, y_steps), z_steps)
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let remainder = x % y;
+        x = y;
+        y = remainder;
+    }
+    a / x * b
+}
+
+fn find_steps_for_axis(mut system: OrbitalSystem) -> usize {
+    let mut steps = 0_usize;
+
+    let initial_state = system.clone();
+
+    loop {
+        steps += 1;
+        system.tick();
+
+        if system.eq(&initial_state) {
+            break;
+        }
+    }
+    steps
+}
+
+type Vector = Point3<i32>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Satellite {
+    position: Vector,
+    velocity: Vector,
+}
+
+impl Display for Satellite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let positions = format!("pos=<x=\t{}, y=\t{}, z=\t{}", self.position.x, self.position.y, self.position.z);
+        let velocities = format!("vel=<x=\t{}, y=\t{}, z=\t{}", self.velocity.x, self.velocity.y, self.velocity.z);
+        write!(f, "{}, {}", positions, velocities)
+    }
+}
+
+impl Satellite {
+    fn new(position: Vector) -> Self {
+        Satellite {
+            position,
+            velocity: Vector::default(),
+        }
+    }
+
+    fn kinetic_energy(&self) -> usize {
+        self.velocity.manhattan(Vector::default()) as usize
+    }
+
+    fn potential_energy(&self) -> usize {
+        self.position.manhattan(Vector::default()) as usize
+    }
+
+    fn total_energy(&self) -> usize {
+        self.kinetic_energy() * self.potential_energy()
+    }
+
+    fn compare_axes(sat: i32, other: i32) -> i32 {
+        match sat.cmp(&other) {
+            Ordering::Equal => 0,
+            Ordering::Greater => -1,
+            Ordering::Less => 1
+        }
+    }
+
+    fn apply_gravity(&mut self, other: &Satellite) {
+        self.velocity.x += Satellite::compare_axes(self.position.x, other.position.x);
+        self.velocity.y += Satellite::compare_axes(self.position.y, other.position.y);
+        self.velocity.z += Satellite::compare_axes(self.position.z, other.position.z);
+    }
+
+    fn apply_velocity(&mut self) {
+        self.position += self.velocity;
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Clone)]
+pub struct OrbitalSystem {
+    moons: Vec<Satellite>
+}
+
+impl Display for OrbitalSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut output = String::new();
+        for moon in &self.moons {
+            output = output.add(format!("{}\n", moon).as_ref());
+        }
+        write!(f, "{}", output)
+    }
+}
+
+impl OrbitalSystem {
+    fn total_energy(&self) -> usize {
+        self.moons.iter()
+            .map(|moon| moon.total_energy())
+            .sum()
+    }
+
+    fn tick(&mut self) {
+        let mut idx_pairs = vec![];
+        for i in 0..self.moons.len() {
+            for k in 0..self.moons.len() {
+                if k != i {
+                    idx_pairs.push((i, k))
+                }
+            }
+        }
+
+        for (left_idx, right_idx) in idx_pairs {
+            let mut left = *self.moons.get(left_idx).unwrap();
+            let right = self.moons.get(right_idx).unwrap();
+            left.apply_gravity(right);
+            self.moons.remove(left_idx);
+            self.moons.insert(left_idx, left);
+        }
+
+        for moon in &mut self.moons {
+            moon.apply_velocity()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_energy() {
+        let mut system = OrbitalSystem {
+            moons: vec![
+                Satellite::new(Vector::new(-1, 0, 2)),
+                Satellite::new(Vector::new(2, -10, -7)),
+                Satellite::new(Vector::new(4, -8, 8)),
+                Satellite::new(Vector::new(3, 5, -1)),
+            ]
+        };
+
+        lib::trace!("{}", system);
+        for _ in 0..10 {
+            system.tick();
+            lib::trace!("{}", system);
+        }
+
+        assert_eq!(system.total_energy(), 179);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(lcm(4, 6), 12);
+
+        let system = OrbitalSystem {
+            moons: vec![
+                Satellite::new(Vector::new(-1, 0, 2)),
+                Satellite::new(Vector::new(2, -10, -7)),
+                Satellite::new(Vector::new(4, -8, 8)),
+                Satellite::new(Vector::new(3, 5, -1)),
+            ]
+        };
+        assert_eq!(part2(system), 2772);
+
+        let system = OrbitalSystem {
+            moons: vec![
+                Satellite::new(Vector::new(-8, -10, 0)),
+                Satellite::new(Vector::new(5, 5, 10)),
+                Satellite::new(Vector::new(2, -7, 3)),
+                Satellite::new(Vector::new(9, -8, -3)),
+            ]
+        };
+        assert_eq!(part2(system), 4686774924);
+    }
+}
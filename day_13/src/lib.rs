@@ -0,0 +1,151 @@
+use intcode::{IntCodeComputer, IoDevice, Memory, MemoryValue};
+use lib::log::Level;
+use lib::solution::Solution;
+use lib::term::{Glyph, Renderer};
+use lib::Grid;
+use std::cmp::Ordering;
+use std::fmt::{Display, Error, Formatter};
+use std::io::{self, Stderr};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Memory;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> usize {
+        let mut cabinet = Cabinet::new();
+        IntCodeComputer::new(program.clone()).run_with(&mut cabinet);
+        cabinet.screen.positions_of(|tile| *tile == Tile::Block).count()
+    }
+
+    fn part2(&self, program: &Memory) -> usize {
+        let mut program = program.clone();
+        program[0] = 2;
+        let mut cabinet = Cabinet::new();
+        IntCodeComputer::new(program).run_with(&mut cabinet);
+        cabinet.print();
+        if let Some(renderer) = &mut cabinet.renderer {
+            renderer.finish().unwrap();
+        }
+        cabinet.score
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+enum Tile {
+    #[default]
+    Empty,
+    Wall,
+    Block,
+    HPaddle,
+    Ball,
+}
+
+impl Tile {
+    fn from_isize(input: isize) -> Self {
+        match input {
+            0 => Tile::Empty,
+            1 => Tile::Wall,
+            2 => Tile::Block,
+            3 => Tile::HPaddle,
+            4 => Tile::Ball,
+            _ => unreachable!()
+        }
+    }
+
+    fn glyph(&self) -> Glyph {
+        match self {
+            Tile::Ball => Glyph::coloured('o', [255, 255, 255]),
+            Tile::Empty => Glyph::new(' '),
+            Tile::HPaddle => Glyph::coloured('_', [80, 160, 255]),
+            Tile::Block => Glyph::coloured('+', [255, 160, 0]),
+            Tile::Wall => Glyph::coloured('x', [128, 128, 128]),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let output = match self {
+            Tile::Ball => ".",
+            Tile::Empty => " ",
+            Tile::HPaddle => "_",
+            Tile::Block => "+",
+            Tile::Wall => "x"
+        };
+        write!(f, "{}", output)
+    }
+}
+
+struct Cabinet {
+    screen: Grid<Tile>,
+    next_operation: Memory,
+    score: usize,
+    ball_position: (usize, usize),
+    paddle_position: (usize, usize),
+    /// Animates the game on stderr, only while tracing.
+    renderer: Option<Renderer<Stderr>>,
+}
+
+impl Cabinet {
+    fn new() -> Self {
+        Cabinet {
+            screen: Grid::new(43, 43, 0, 0),
+            next_operation: vec![],
+            score: 0,
+            ball_position: (0, 0),
+            paddle_position: (0, 0),
+            renderer: if lib::log_enabled!(Level::Trace) {
+                Some(Renderer::new(io::stderr()))
+            } else {
+                None
+            },
+        }
+    }
+
+    fn print(&mut self) {
+        if let Some(renderer) = &mut self.renderer {
+            let status = format!("Score: {}", self.score);
+            renderer.draw(&self.screen, Tile::glyph, &status).unwrap();
+        }
+    }
+}
+
+impl IoDevice for Cabinet {
+    fn input(&mut self) -> Option<MemoryValue> {
+        self.print();
+        let next_input = match self.paddle_position.0.cmp(&self.ball_position.0) {
+            Ordering::Greater => -1,
+            Ordering::Equal => 0,
+            Ordering::Less => 1
+        };
+        Some(next_input)
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        self.next_operation.push(value);
+
+        if self.next_operation.len() == 3 {
+            let (x, y, value) = (self.next_operation[0], self.next_operation[1], self.next_operation[2]);
+            if x == -1 && y == 0 {
+                self.score = value as usize;
+            } else {
+                let tile = Tile::from_isize(value);
+                let pos = (x as usize, y as usize);
+                match tile {
+                    Tile::Ball => self.ball_position = pos,
+                    Tile::HPaddle => self.paddle_position = pos,
+                    _ => ()
+                }
+                self.screen.set(x, y, tile).unwrap();
+            }
+            self.next_operation.clear();
+        }
+    }
+}
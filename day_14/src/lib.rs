@@ -0,0 +1,266 @@
+use lib::solution::Solution;
+use std::collections::HashMap;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = IngredientMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<IngredientMap, String> {
+        parse_input(input)
+    }
+
+    fn part1(&self, ingredient_map: &IngredientMap) -> usize {
+        part1(ingredient_map.clone())
+    }
+
+    fn part2(&self, ingredient_map: &IngredientMap) -> usize {
+        part2(ingredient_map.clone(), 1_000_000_000_000)
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Item {
+    quantity: usize,
+    name: String,
+}
+
+impl Item {
+    fn is_ore(&self) -> bool {
+        self.name.eq(&String::from("ORE"))
+    }
+}
+
+impl std::ops::Mul<usize> for Item {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        Item {
+            quantity: self.quantity * rhs,
+            name: self.name,
+        }
+    }
+}
+
+type IngredientMap = HashMap<Item, Vec<Item>>;
+
+fn part1(input: IngredientMap) -> usize {
+    let start_item = Item {
+        quantity: 1,
+        name: String::from("FUEL"),
+    };
+    resolve(&input, &start_item)
+}
+
+fn part2(input: IngredientMap, target: usize) -> usize {
+    let x = move |quantity: usize| {
+        let fuel_item = Item { name: String::from("FUEL"), quantity };
+        let required_ore = resolve(&input, &fuel_item);
+        lib::debug!("Producing {} fuel takes {} ore", quantity, required_ore);
+        required_ore
+    };
+
+    let cost_one = x(1);
+    let mut fuel_left = target / cost_one;
+    let mut fuel_right = fuel_left * 2;
+
+    while fuel_right - fuel_left > 1 {
+        let fuel = (fuel_left + fuel_right) / 2;
+        let required_ore = x(fuel);
+
+        if required_ore < target {
+            fuel_left = fuel;
+        } else {
+            fuel_right = fuel;
+        }
+    }
+
+    fuel_left
+}
+
+fn try_leftovers(leftovers: &mut HashMap<String, usize>, item: &Item) -> usize {
+    let required = item.quantity;
+    if let Some(leftover) = leftovers.get_mut(&item.name) {
+        if *leftover >= required {
+            *leftover -= required;
+            0
+        } else {
+            let remainder = required - *leftover;
+            leftovers.remove(&item.name);
+            remainder
+        }
+    } else {
+        required
+    }
+}
+
+fn resolve(ingredient_map: &IngredientMap, item: &Item) -> usize {
+    let mut queue: Vec<Item> = vec![item.clone()];
+    let mut leftovers: HashMap<String, usize> = HashMap::new();
+    let mut ore: usize = 0;
+
+    while let Some(next) = queue.pop() {
+        if next.is_ore() {
+            ore += next.quantity;
+            continue;
+        }
+
+        let remaining = try_leftovers(&mut leftovers, &next);
+        if remaining > 0 {
+            let minimum_production_size = ingredient_map
+                .keys()
+                .filter(|i| i.name.eq(&next.name))
+                .cloned()
+                .collect::<Vec<Item>>()
+                .first()
+                .unwrap()
+                .quantity;
+
+            let factor = (remaining as f64 / minimum_production_size as f64).ceil() as usize;
+
+            let n_produced = minimum_production_size * factor;
+            lib::trace!("Producing {} {} for {} needed", n_produced, next.name, remaining);
+
+            // Save leftovers
+            if n_produced > remaining {
+                let leftover = n_produced - remaining;
+                *leftovers.entry(next.name.clone()).or_insert(0) += leftover;
+            }
+
+            // Schedule production for ingredients in quantities based on factor
+            let ingredients = get_ingredients(ingredient_map, &next);
+            for i in &ingredients {
+                queue.push(i.clone() * factor );
+            }
+        }
+    }
+
+    ore
+}
+
+fn get_ingredients(ingredient_map: &IngredientMap, item: &Item) -> Vec<Item> {
+    let ancestor_item = ingredient_map
+        .keys()
+        .filter(|i| i.name.eq(&item.name))
+        .collect::<Vec<&Item>>()
+        .first()
+        .copied()
+        .unwrap();
+
+    ingredient_map.get(ancestor_item).unwrap().clone()
+}
+
+fn parse_token(input: &str) -> Result<Item, String> {
+    let invalid = || format!("Expected a quantity and a chemical like '7 A', found '{}'", input.trim());
+    let (quantity, name) = input.trim().split_once(' ').ok_or_else(invalid)?;
+    match quantity.parse() {
+        Ok(quantity) if quantity > 0 && !name.is_empty() => Ok(Item {
+            quantity,
+            name: name.to_string(),
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<Item, Vec<Item>>, String> {
+    let ingredient_map = lib::input::lines(input)
+        .map(|line| {
+            let (ingredients, produced_item) = line
+                .split_once("=>")
+                .ok_or_else(|| format!("Expected a reaction like '7 A, 1 B => 1 C', found '{}'", line))?;
+            let ingredients = ingredients
+                .split(",")
+                .map(parse_token)
+                .collect::<Result<Vec<Item>, String>>()?;
+            Ok((parse_token(produced_item)?, ingredients))
+        })
+        .collect::<Result<HashMap<Item, Vec<Item>>, String>>()?;
+
+    let is_produced = |name: &str| ingredient_map.keys().any(|item| item.name == name);
+    let missing = std::iter::once("FUEL")
+        .chain(ingredient_map.values().flatten().map(|item| item.name.as_str()))
+        .find(|name| *name != "ORE" && !is_produced(name));
+    match missing {
+        Some(name) => Err(format!("No reaction produces {}", name)),
+        None => Ok(ingredient_map),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, resolve, Item};
+
+    fn test(input: &'static str, fuel: usize) {
+        let _parsed = parse_input(input);
+        let result = resolve(
+            &parse_input(input).unwrap(),
+            &Item {
+                quantity: 1,
+                name: String::from("FUEL"),
+            }
+        );
+        assert_eq!(result, fuel);
+    }
+
+    #[test]
+    fn test_simple() {
+        let input = "\
+            9 ORE => 2 A
+            8 ORE => 3 B
+            7 ORE => 5 C
+            3 A, 4 B => 1 AB
+            5 B, 7 C => 1 BC
+            4 C, 1 A => 1 CA
+            2 AB, 3 BC, 4 CA => 1 FUEL";
+        test(input, 165);
+    }
+
+    #[test]
+    fn test_invalid_reactions() {
+        assert_eq!(parse_input("9 ORE => 2 A"), Err(String::from("No reaction produces FUEL")));
+        assert_eq!(parse_input("1 B => 1 FUEL"), Err(String::from("No reaction produces B")));
+        assert_eq!(
+            parse_input("9 ORE -> 1 FUEL"),
+            Err(String::from("Expected a reaction like '7 A, 1 B => 1 C', found '9 ORE -> 1 FUEL'"))
+        );
+        assert_eq!(
+            parse_input("ORE => 1 FUEL"),
+            Err(String::from("Expected a quantity and a chemical like '7 A', found 'ORE'"))
+        );
+    }
+
+    #[test]
+    fn test_medium() {
+        let input = "\
+            157 ORE => 5 NZVS
+            165 ORE => 6 DCFZ
+            44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+            12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+            179 ORE => 7 PSHF
+            177 ORE => 5 HKGWZ
+            7 DCFZ, 7 PSHF => 2 XJWVT
+            165 ORE => 2 GPVTF
+            3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT";
+        test(input, 13312);
+    }
+
+    #[test]
+    fn test_large() {
+        let input = "\
+            145 ORE => 6 MNCFX
+            176 ORE => 6 VJHF
+            139 ORE => 4 NVRVD
+            144 ORE => 7 JNWZP
+            1 NVRVD => 8 CXFTF
+            17 NVRVD, 3 JNWZP => 8 VPVL
+            22 VJHF, 37 MNCFX => 5 FWMGM
+            1 VJHF, 6 MNCFX => 4 RFSQX
+            2 VPVL, 7 FWMGM, 2 CXFTF, 11 MNCFX => 1 STKFG
+            5 VJHF, 7 MNCFX, 9 VPVL, 37 CXFTF => 6 GNMV
+            5 MNCFX, 7 RFSQX, 2 FWMGM, 2 VPVL, 19 CXFTF => 3 HVMC
+            53 STKFG, 6 MNCFX, 46 VJHF, 81 HVMC, 68 CXFTF, 25 GNMV => 1 FUEL";
+        test(input, 180697);
+    }
+}
@@ -0,0 +1,178 @@
+use intcode::{IntCodeComputer, IoDevice, Memory, MemoryValue};
+use lib::geom::{Direction, Point2};
+use lib::search::Bfs;
+use lib::solution::Solution;
+use lib::SparseGrid;
+use std::fmt::{Display, Error, Formatter};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Memory;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> usize {
+        part1(program.clone())
+    }
+
+    fn part2(&self, program: &Memory) -> usize {
+        part2(program.clone())
+    }
+}
+
+fn part1(program: Memory) -> usize {
+    let mut ctrl = Controller::new();
+    let (_, steps) = ctrl.find_oxygen_system(program).unwrap();
+    steps
+}
+
+fn part2(program: Memory) -> usize {
+    let mut ctrl = Controller::new();
+    let (droid, _) = ctrl.find_oxygen_system(program).unwrap();
+    let oxygen_system = droid_position(&droid);
+    ctrl.map_area(droid);
+    ctrl.grid
+        .flood_fill(oxygen_system.into(), |block| !matches!(block, Block::Wall))
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+/// The movement command the repair droid expects for a direction.
+fn command(direction: Direction) -> MemoryValue {
+    match direction {
+        Direction::North => 1,
+        Direction::South => 2,
+        Direction::West => 3,
+        Direction::East => 4,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Robot {
+    position: Point2<isize>,
+    next_direction: Option<Direction>,
+    status: Option<Block>,
+}
+
+impl Robot {
+    fn new(position: Point2<isize>) -> Robot {
+        Robot {
+            position,
+            next_direction: None,
+            status: None,
+        }
+    }
+}
+
+impl IoDevice for Robot {
+    fn input(&mut self) -> Option<MemoryValue> {
+        let direction = self.next_direction.take()?;
+        self.position += direction;
+        Some(command(direction))
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        self.status = Some(Block::from_isize(value));
+    }
+}
+
+/// A robot together with the computer steering it.
+type Droid = (IntCodeComputer, Robot);
+
+fn droid_position(droid: &Droid) -> Point2<isize> {
+    droid.1.position
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Block {
+    Wall,
+    #[default]
+    Nothing,
+    Goal,
+    Start,
+}
+
+impl Block {
+    fn from_isize(value: isize) -> Self {
+        match value {
+            0 => Block::Wall,
+            1 => Block::Nothing,
+            2 => Block::Goal,
+            _ => unreachable!(),
+        }
+    }
+
+    fn to_str(self) -> &'static str {
+        match self {
+            Block::Wall => "#",
+            Block::Nothing => " ",
+            Block::Goal => "G",
+            Block::Start => "S",
+        }
+    }
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.to_str())
+    }
+}
+
+struct Controller {
+    grid: SparseGrid<Block>,
+}
+
+impl Controller {
+    fn new() -> Self {
+        let grid = SparseGrid::new();
+
+        Controller { grid }
+    }
+
+    /// Explores the maze breadth-first, returning the droid which reached the oxygen
+    /// system and the number of steps it took.
+    fn find_oxygen_system(&mut self, program: Memory) -> Option<(Droid, usize)> {
+        let start = (IntCodeComputer::new(program), Robot::new(Point2::default()));
+        self.grid.set(0, 0, Block::Start);
+
+        Bfs::with_key(start, |droid| self.explore(droid), droid_position)
+            .find(|((_, robot), _)| matches!(robot.status, Some(Block::Goal)))
+    }
+
+    /// Walks to every reachable position, recording the whole maze in the grid.
+    fn map_area(&mut self, start: Droid) {
+        Bfs::with_key(start, |droid| self.explore(droid), droid_position).for_each(drop);
+    }
+
+    /// Tries every direction from the droid's position, returning the droids which moved.
+    fn explore(&mut self, droid: &Droid) -> Vec<Droid> {
+        Direction::ALL
+            .iter()
+            .filter_map(|direction| self.try_direction(droid, *direction))
+            .collect()
+    }
+
+    fn try_direction(&mut self, droid: &Droid, direction: Direction) -> Option<Droid> {
+        let (cpu, robot) = droid;
+
+        let mut new_cpu = cpu.clone();
+        let mut new_robot = robot.clone();
+        new_robot.next_direction = Some(direction);
+        new_cpu.run_with(&mut new_robot);
+        let block_state = new_robot.status.unwrap();
+
+        let Point2 { x, y } = new_robot.position;
+        self.grid.set(x, y, block_state);
+
+        match block_state {
+            Block::Wall => None,
+            _ => Some((new_cpu, new_robot)),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_16::Day16;
+use lib::solution::Solution;
+
+const INPUT: &str = include_str!("../../fixtures/day16.txt");
+
+fn fft(c: &mut Criterion) {
+    let signal = Day16.parse(INPUT).unwrap();
+    let mut group = c.benchmark_group("day_16");
+    group.sample_size(10);
+    group.bench_function("part 1", |b| b.iter(|| Day16.part1(black_box(&signal))));
+    group.bench_function("part 2", |b| b.iter(|| Day16.part2(black_box(&signal))));
+    group.finish();
+}
+
+criterion_group!(benches, fft);
+criterion_main!(benches);
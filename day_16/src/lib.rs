@@ -0,0 +1,127 @@
+use lib::solution::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<u32>, String> {
+        let signal = input
+            .trim()
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .ok_or_else(|| format!("'{}' is not a digit", c))
+            })
+            .collect::<Result<Vec<u32>, String>>()?;
+        if signal.len() < 8 {
+            return Err(format!("A signal of {} digits is too short", signal.len()));
+        }
+        Ok(signal)
+    }
+
+    fn part1(&self, input: &Vec<u32>) -> usize {
+        let output = part1(input.clone(), 100);
+        vec_to_num(&output[0..8])
+    }
+
+    fn part2(&self, input: &Vec<u32>) -> usize {
+        part2(input.clone())
+    }
+}
+
+fn part1(mut input: Vec<u32>, n_phases: usize) -> Vec<u32> {
+    for _ in 0..n_phases {
+        input = calculate_fft(input);
+    }
+    input
+}
+
+// Credits to https://github.com/prscoelho/aoc2019/blob/master/src/aoc16/mod.rs
+fn part2(input: Vec<u32>) -> usize {
+    let offset_message: &[u32] = &input[0..7];
+    let offset = vec_to_num(offset_message);
+
+    let start = offset;
+    let end = input.len() * 10_000;
+
+    let mut current = Vec::new();
+    for i in start..end {
+        current.push(input[i % input.len()]);
+    }
+
+    for _ in 0..100 {
+        let mut sums = Vec::new();
+        let mut total = 0;
+        sums.push(0);
+        for value in &current {
+            total += value;
+            sums.push(total);
+        }
+        for (i, value) in current.iter_mut().enumerate() {
+            *value = (sums.last().unwrap() - sums[i]) % 10;
+        }
+    }
+
+    vec_to_num(&current[0..8])
+}
+
+fn vec_to_num(vec: &[u32]) -> usize {
+    vec.iter().fold(0u32, |acc, x| acc * 10 + *x) as usize
+}
+
+fn calculate_fft(input: Vec<u32>) -> Vec<u32> {
+    let mut output: Vec<u32> = Vec::with_capacity(input.len());
+
+    for idx in 0..input.len() {
+        let v = calculate_fft_idx(input.as_slice(), idx);
+        output.push(v);
+    }
+
+    output
+}
+
+fn calculate_fft_idx(input: &[u32], index: usize) -> u32 {
+    let base_pattern: Vec<i32> = vec![0, 1, 0, -1];
+    let mut pattern: Vec<i32> = vec![];
+
+    for base_element in &base_pattern {
+        for _ in 0..(index + 1) {
+            pattern.push(*base_element);
+        }
+    }
+
+    let pattern_length = input.len() + 1;
+
+    let x: i32 = input
+        .iter()
+        .zip(pattern.iter().cycle().take(pattern_length).skip(1))
+        .map(|(left, right)| *left as i32 * *right)
+        .sum();
+
+    (x % 10).unsigned_abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{part1, vec_to_num};
+
+    #[test]
+    fn test_phase_1() {
+        let input = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let expected_phase1 = vec![4, 8, 2, 2, 6, 1, 5, 8];
+        assert_eq!(part1(input, 1), expected_phase1);
+
+        let expected_phase2 = vec![3, 4, 0, 4, 0, 4, 3, 8];
+        assert_eq!(part1(expected_phase1, 1), expected_phase2);
+    }
+
+    #[test]
+    fn test_vec_to_num() {
+        let input = vec![4, 3, 2, 1];
+        let expected: usize = 4321;
+        assert_eq!(vec_to_num(&input), expected)
+    }
+}
@@ -0,0 +1,185 @@
+use intcode::{IntCodeComputer, Memory, State};
+use lib::log::Level;
+use lib::solution::Solution;
+use lib::term::{Glyph, Renderer};
+use lib::{CharCell, Grid, ParseGridError, SparseGrid, NEIGHBOURS4};
+use std::collections::HashSet;
+use std::fmt::{Display, Error, Formatter};
+use std::io::{self, Stderr};
+use std::str::FromStr;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Memory;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> isize {
+        part1(program.clone())
+    }
+
+    fn part2(&self, program: &Memory) -> isize {
+        part2(program.clone())
+    }
+}
+
+fn part1(program: Memory) -> isize {
+    let mut program = program;
+    *program.get_mut(0).unwrap() = 1;
+    let mut cpu = IntCodeComputer::new(program);
+    let mut output = Vec::new();
+    loop {
+        match cpu.step() {
+            State::Output(o) => output.push(o),
+            State::Halt => break,
+            _ => (),
+        }
+    }
+
+    let text = output
+        .iter()
+        .map(|n| match *n {
+            35 => '#',
+            46 => '.',
+            10 => '\n',
+            60 => '<',
+            62 => '>',
+            94 => '^',
+            88 => 'X',
+            _ => unreachable!(),
+        })
+        .collect::<String>();
+
+    lib::debug!("Camera view:\n{}", text);
+
+    let grid = build_grid(&text).expect("The camera only shows scaffolds and the robot");
+    let intersections = find_intersections(grid);
+
+    intersections.iter().map(|(x, y)| *x * *y).sum::<isize>()
+}
+
+fn part2(program: Memory) -> isize {
+    let mut cpu = IntCodeComputer::new(program);
+
+    let cpu_input =
+        String::from("C,B,C,B,A,A,B,C,B,A\nL,10,R,12,R,8\nR,8,R,10,R,12\nL,12,L,10,R,8,L,12\nn\n");
+    let mut input = cpu_input
+        .chars()
+        .rev()
+        .collect::<String>();
+
+    // Animates the camera feed on stderr, only while tracing
+    let mut renderer = if lib::log_enabled!(Level::Trace) {
+        Some(Renderer::new(io::stderr()))
+    } else {
+        None
+    };
+    let mut output = String::new();
+    let mut dust_collected = 0;
+    loop {
+        match cpu.step() {
+            State::Output(o) => {
+                if o > 255 {
+                    dust_collected = o;
+                }
+                let char = char::from(o as u8);
+                let line_n: usize = output.split('\n').count();
+                if char.eq(&'\n') && line_n.eq(&66) {
+                    if let Some(renderer) = &mut renderer {
+                        draw_frame(renderer, &output);
+                    }
+                    output.clear();
+                } else {
+                    output.push(char);
+                }
+            }
+            State::Halt => {
+                if let Some(renderer) = &mut renderer {
+                    renderer.finish().unwrap();
+                }
+                return dust_collected;
+            }
+            State::WaitingForInput => {
+                let c = input.pop().unwrap();
+                lib::trace!("Input: {:?}", c);
+                cpu.read_input(c as isize)
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Draws one frame of the camera feed, skipping frames which are not a map.
+fn draw_frame(renderer: &mut Renderer<Stderr>, frame: &str) {
+    match Grid::<char>::from_str(frame) {
+        Ok(frame) => {
+            let glyph = |c: &char| Glyph::new(if *c == '\0' { ' ' } else { *c });
+            renderer.draw(&frame, glyph, "").unwrap();
+        }
+        Err(error) => lib::warn!("Skipping a camera frame: {}", error),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+enum Block {
+    #[default]
+    Empty,
+    Scaffold,
+    Robot,
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl CharCell for Block {
+    fn from_char(c: char) -> Option<Block> {
+        match c {
+            '#' => Some(Block::Scaffold),
+            '.' => Some(Block::Empty),
+            '^' | '<' | '>' | 'X' => Some(Block::Robot),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Block::Empty => '.',
+            Block::Scaffold => '#',
+            Block::Robot => 'R',
+        }
+    }
+}
+
+fn build_grid(output: &str) -> Result<SparseGrid<Block>, ParseGridError> {
+    let mut grid = SparseGrid::new();
+
+    for item in Grid::<Block>::from_str(output)? {
+        if item.element != Block::Empty {
+            grid.set(item.x, item.y, item.element);
+        }
+    }
+
+    Ok(grid)
+}
+
+fn find_intersections(grid: SparseGrid<Block>) -> HashSet<(isize, isize)> {
+    grid.cells()
+        .map(|(position, _)| position)
+        .filter(|&(x, y)| is_block_intersection(&grid, x, y))
+        .collect()
+}
+
+fn is_block_intersection(grid: &SparseGrid<Block>, x: isize, y: isize) -> bool {
+    grid.get(x, y) == Some(&Block::Scaffold)
+        && NEIGHBOURS4
+            .iter()
+            .all(|(dx, dy)| grid.get(x + dx, y + dy) == Some(&Block::Scaffold))
+}
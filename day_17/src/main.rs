@@ -1,258 +1,5 @@
-use intcode::{IntCodeComputer, Memory, State};
-use lib::{Grid, GridIteratorItem};
-use std::collections::HashSet;
-use std::fmt::{Display, Error, Formatter};
+use day_17::Day17;
 
 fn main() {
-    //part1(input());
-    part2(input());
-}
-
-fn part1(program: Memory) {
-    let mut program = program;
-    *program.get_mut(0).unwrap() = 1;
-    let mut cpu = IntCodeComputer::new(program);
-    let mut output = Vec::new();
-    loop {
-        match cpu.step() {
-            State::Output(o) => output.push(o),
-            State::Halt => break,
-            _ => (),
-        }
-    }
-
-    let text = output
-        .iter()
-        .map(|n| match *n {
-            35 => '#',
-            46 => '.',
-            10 => '\n',
-            60 => '<',
-            62 => '>',
-            94 => '^',
-            88 => 'X',
-            _ => unreachable!(),
-        })
-        .collect::<String>();
-
-    println!("{} {}", text, text.len());
-
-    let grid = build_grid(text);
-    let intersections = find_intersections(grid);
-
-    let result = intersections.iter().map(|(x, y)| *x * *y).sum::<isize>();
-    println!(
-        "Found {} intersections, value is: {}",
-        intersections.len(),
-        result
-    );
-}
-
-fn part2(program: Memory) {
-    let mut cpu = IntCodeComputer::new(program);
-
-    let cpu_input =
-        String::from("C,B,C,B,A,A,B,C,B,A\nL,10,R,12,R,8\nR,8,R,10,R,12\nL,12,L,10,R,8,L,12\nn\n");
-    let mut input = cpu_input
-        .chars()
-        .rev()
-        //        .map(|x| {
-        //            println!("Char: {:?}, ASCII: {:?}", x, x as isize);
-        //            x
-        //        })
-        .collect::<String>();
-
-    let mut output = String::new();
-    loop {
-        match cpu.step() {
-            State::Output(o) => {
-                if o > 255 {
-                    println!("Dust collected: {}", o);
-                }
-                let char = char::from(o as u8);
-                let line_n: usize = output.split('\n').count();
-                if char.eq(&'\n') && line_n.eq(&66) {
-                    println!("\x1B[2J{}", output);
-                    output.clear();
-                } else {
-                    output.push(char);
-                }
-            }
-            State::Halt => break,
-            State::WaitingForInput => {
-                let c = input.pop().unwrap();
-                // println!("Input: {}, {}", c, c as u32);
-                cpu.read_input(c as isize)
-            }
-            _ => (),
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-enum Block {
-    Empty,
-    Scaffold,
-    Robot,
-}
-
-impl Display for Block {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}", self.to_str())
-    }
-}
-
-impl Default for Block {
-    fn default() -> Self {
-        Block::Empty
-    }
-}
-
-impl Block {
-    fn from_str(input: &str) -> Block {
-        match input {
-            "#" => Block::Scaffold,
-            "." => Block::Empty,
-            "^" | "<" | ">" | "X" => Block::Robot,
-            _ => unreachable!(),
-        }
-    }
-
-    fn to_str(&self) -> &str {
-        match self {
-            Block::Empty => ".",
-            Block::Scaffold => "#",
-            Block::Robot => "R",
-        }
-    }
-}
-
-fn build_grid(output: String) -> Grid<Block> {
-    let lines: Vec<String> = output
-        .split('\n')
-        .map(String::from)
-        .collect::<Vec<String>>();
-
-    let width = lines[0].len();
-    let height = lines.len();
-
-    let mut grid: Grid<Block> = Grid::new(width, height, 0, 0);
-
-    for (col, line) in lines.iter().enumerate() {
-        for (row, char) in line.trim().chars().enumerate() {
-            grid.set(
-                row as isize,
-                col as isize,
-                Block::from_str(char.to_string().as_str()),
-            )
-            .unwrap();
-        }
-    }
-
-    grid
-}
-
-fn find_intersections(grid: Grid<Block>) -> HashSet<(isize, isize)> {
-    let mut intersections = HashSet::new();
-    let grid2 = grid.clone();
-    for block in grid.into_iter() {
-        if is_block_intersection(&grid2, &block) {
-            intersections.insert((block.x, block.y));
-        }
-    }
-    intersections
-}
-
-fn is_block_intersection(grid: &Grid<Block>, block: &GridIteratorItem<Block>) -> bool {
-    let x = block.x;
-    let y = block.y;
-    let neighbours = vec![
-        grid.get(x, y),
-        grid.get(x - 1, y),
-        grid.get(x, y - 1),
-        grid.get(x + 1, y),
-        grid.get(x, y + 1),
-    ];
-
-    neighbours
-        .iter()
-        .flatten()
-        .all(|x| (**x).eq(&Block::Scaffold))
-}
-
-fn input() -> Memory {
-    vec![
-        2, 330, 331, 332, 109, 4286, 1102, 1, 1182, 16, 1101, 1491, 0, 24, 102, 1, 0, 570, 1006,
-        570, 36, 1002, 571, 1, 0, 1001, 570, -1, 570, 1001, 24, 1, 24, 1106, 0, 18, 1008, 571, 0,
-        571, 1001, 16, 1, 16, 1008, 16, 1491, 570, 1006, 570, 14, 21102, 58, 1, 0, 1105, 1, 786,
-        1006, 332, 62, 99, 21101, 0, 333, 1, 21101, 0, 73, 0, 1105, 1, 579, 1102, 0, 1, 572, 1102,
-        1, 0, 573, 3, 574, 101, 1, 573, 573, 1007, 574, 65, 570, 1005, 570, 151, 107, 67, 574, 570,
-        1005, 570, 151, 1001, 574, -64, 574, 1002, 574, -1, 574, 1001, 572, 1, 572, 1007, 572, 11,
-        570, 1006, 570, 165, 101, 1182, 572, 127, 1002, 574, 1, 0, 3, 574, 101, 1, 573, 573, 1008,
-        574, 10, 570, 1005, 570, 189, 1008, 574, 44, 570, 1006, 570, 158, 1106, 0, 81, 21101, 0,
-        340, 1, 1106, 0, 177, 21101, 0, 477, 1, 1106, 0, 177, 21102, 514, 1, 1, 21102, 176, 1, 0,
-        1106, 0, 579, 99, 21102, 1, 184, 0, 1105, 1, 579, 4, 574, 104, 10, 99, 1007, 573, 22, 570,
-        1006, 570, 165, 101, 0, 572, 1182, 21101, 0, 375, 1, 21101, 0, 211, 0, 1106, 0, 579, 21101,
-        1182, 11, 1, 21101, 0, 222, 0, 1105, 1, 979, 21102, 388, 1, 1, 21102, 233, 1, 0, 1106, 0,
-        579, 21101, 1182, 22, 1, 21102, 244, 1, 0, 1105, 1, 979, 21101, 0, 401, 1, 21102, 255, 1,
-        0, 1105, 1, 579, 21101, 1182, 33, 1, 21101, 266, 0, 0, 1106, 0, 979, 21101, 414, 0, 1,
-        21102, 277, 1, 0, 1106, 0, 579, 3, 575, 1008, 575, 89, 570, 1008, 575, 121, 575, 1, 575,
-        570, 575, 3, 574, 1008, 574, 10, 570, 1006, 570, 291, 104, 10, 21101, 0, 1182, 1, 21101,
-        313, 0, 0, 1105, 1, 622, 1005, 575, 327, 1101, 0, 1, 575, 21102, 1, 327, 0, 1105, 1, 786,
-        4, 438, 99, 0, 1, 1, 6, 77, 97, 105, 110, 58, 10, 33, 10, 69, 120, 112, 101, 99, 116, 101,
-        100, 32, 102, 117, 110, 99, 116, 105, 111, 110, 32, 110, 97, 109, 101, 32, 98, 117, 116,
-        32, 103, 111, 116, 58, 32, 0, 12, 70, 117, 110, 99, 116, 105, 111, 110, 32, 65, 58, 10, 12,
-        70, 117, 110, 99, 116, 105, 111, 110, 32, 66, 58, 10, 12, 70, 117, 110, 99, 116, 105, 111,
-        110, 32, 67, 58, 10, 23, 67, 111, 110, 116, 105, 110, 117, 111, 117, 115, 32, 118, 105,
-        100, 101, 111, 32, 102, 101, 101, 100, 63, 10, 0, 37, 10, 69, 120, 112, 101, 99, 116, 101,
-        100, 32, 82, 44, 32, 76, 44, 32, 111, 114, 32, 100, 105, 115, 116, 97, 110, 99, 101, 32,
-        98, 117, 116, 32, 103, 111, 116, 58, 32, 36, 10, 69, 120, 112, 101, 99, 116, 101, 100, 32,
-        99, 111, 109, 109, 97, 32, 111, 114, 32, 110, 101, 119, 108, 105, 110, 101, 32, 98, 117,
-        116, 32, 103, 111, 116, 58, 32, 43, 10, 68, 101, 102, 105, 110, 105, 116, 105, 111, 110,
-        115, 32, 109, 97, 121, 32, 98, 101, 32, 97, 116, 32, 109, 111, 115, 116, 32, 50, 48, 32,
-        99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 33, 10, 94, 62, 118, 60, 0, 1, 0, -1, -1, 0,
-        1, 0, 0, 0, 0, 0, 0, 1, 42, 16, 0, 109, 4, 1202, -3, 1, 586, 21001, 0, 0, -1, 22101, 1, -3,
-        -3, 21102, 1, 0, -2, 2208, -2, -1, 570, 1005, 570, 617, 2201, -3, -2, 609, 4, 0, 21201, -2,
-        1, -2, 1106, 0, 597, 109, -4, 2106, 0, 0, 109, 5, 1202, -4, 1, 629, 21002, 0, 1, -2, 22101,
-        1, -4, -4, 21102, 1, 0, -3, 2208, -3, -2, 570, 1005, 570, 781, 2201, -4, -3, 653, 20101, 0,
-        0, -1, 1208, -1, -4, 570, 1005, 570, 709, 1208, -1, -5, 570, 1005, 570, 734, 1207, -1, 0,
-        570, 1005, 570, 759, 1206, -1, 774, 1001, 578, 562, 684, 1, 0, 576, 576, 1001, 578, 566,
-        692, 1, 0, 577, 577, 21101, 0, 702, 0, 1106, 0, 786, 21201, -1, -1, -1, 1106, 0, 676, 1001,
-        578, 1, 578, 1008, 578, 4, 570, 1006, 570, 724, 1001, 578, -4, 578, 21102, 1, 731, 0, 1106,
-        0, 786, 1106, 0, 774, 1001, 578, -1, 578, 1008, 578, -1, 570, 1006, 570, 749, 1001, 578, 4,
-        578, 21102, 1, 756, 0, 1106, 0, 786, 1106, 0, 774, 21202, -1, -11, 1, 22101, 1182, 1, 1,
-        21102, 1, 774, 0, 1105, 1, 622, 21201, -3, 1, -3, 1105, 1, 640, 109, -5, 2106, 0, 0, 109,
-        7, 1005, 575, 802, 21002, 576, 1, -6, 20101, 0, 577, -5, 1106, 0, 814, 21102, 0, 1, -1,
-        21102, 1, 0, -5, 21101, 0, 0, -6, 20208, -6, 576, -2, 208, -5, 577, 570, 22002, 570, -2,
-        -2, 21202, -5, 43, -3, 22201, -6, -3, -3, 22101, 1491, -3, -3, 2101, 0, -3, 843, 1005, 0,
-        863, 21202, -2, 42, -4, 22101, 46, -4, -4, 1206, -2, 924, 21102, 1, 1, -1, 1106, 0, 924,
-        1205, -2, 873, 21102, 1, 35, -4, 1105, 1, 924, 1202, -3, 1, 878, 1008, 0, 1, 570, 1006,
-        570, 916, 1001, 374, 1, 374, 1202, -3, 1, 895, 1102, 2, 1, 0, 1201, -3, 0, 902, 1001, 438,
-        0, 438, 2202, -6, -5, 570, 1, 570, 374, 570, 1, 570, 438, 438, 1001, 578, 558, 921, 21001,
-        0, 0, -4, 1006, 575, 959, 204, -4, 22101, 1, -6, -6, 1208, -6, 43, 570, 1006, 570, 814,
-        104, 10, 22101, 1, -5, -5, 1208, -5, 65, 570, 1006, 570, 810, 104, 10, 1206, -1, 974, 99,
-        1206, -1, 974, 1101, 0, 1, 575, 21101, 973, 0, 0, 1106, 0, 786, 99, 109, -7, 2105, 1, 0,
-        109, 6, 21101, 0, 0, -4, 21101, 0, 0, -3, 203, -2, 22101, 1, -3, -3, 21208, -2, 82, -1,
-        1205, -1, 1030, 21208, -2, 76, -1, 1205, -1, 1037, 21207, -2, 48, -1, 1205, -1, 1124,
-        22107, 57, -2, -1, 1205, -1, 1124, 21201, -2, -48, -2, 1106, 0, 1041, 21102, -4, 1, -2,
-        1105, 1, 1041, 21101, -5, 0, -2, 21201, -4, 1, -4, 21207, -4, 11, -1, 1206, -1, 1138, 2201,
-        -5, -4, 1059, 2102, 1, -2, 0, 203, -2, 22101, 1, -3, -3, 21207, -2, 48, -1, 1205, -1, 1107,
-        22107, 57, -2, -1, 1205, -1, 1107, 21201, -2, -48, -2, 2201, -5, -4, 1090, 20102, 10, 0,
-        -1, 22201, -2, -1, -2, 2201, -5, -4, 1103, 2101, 0, -2, 0, 1105, 1, 1060, 21208, -2, 10,
-        -1, 1205, -1, 1162, 21208, -2, 44, -1, 1206, -1, 1131, 1106, 0, 989, 21102, 1, 439, 1,
-        1106, 0, 1150, 21102, 1, 477, 1, 1106, 0, 1150, 21102, 514, 1, 1, 21101, 0, 1149, 0, 1105,
-        1, 579, 99, 21101, 0, 1157, 0, 1105, 1, 579, 204, -2, 104, 10, 99, 21207, -3, 22, -1, 1206,
-        -1, 1138, 1201, -5, 0, 1176, 2102, 1, -4, 0, 109, -6, 2105, 1, 0, 4, 11, 32, 1, 9, 1, 32,
-        1, 9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 13, 40, 1, 1,
-        1, 40, 1, 1, 1, 40, 1, 1, 1, 40, 11, 34, 1, 7, 1, 34, 1, 7, 1, 34, 1, 7, 1, 34, 11, 3, 13,
-        24, 1, 1, 1, 3, 1, 36, 1, 1, 1, 3, 1, 36, 1, 1, 1, 3, 1, 36, 1, 1, 1, 3, 1, 36, 1, 1, 1, 3,
-        1, 36, 1, 1, 1, 3, 1, 36, 1, 1, 1, 3, 1, 28, 9, 1, 1, 3, 1, 28, 1, 9, 1, 3, 1, 16, 9, 3, 1,
-        5, 9, 16, 1, 7, 1, 3, 1, 5, 1, 3, 1, 20, 1, 7, 1, 1, 13, 20, 1, 7, 1, 1, 1, 1, 1, 5, 1, 24,
-        1, 7, 1, 1, 1, 1, 1, 5, 1, 24, 1, 7, 1, 1, 1, 1, 1, 5, 1, 24, 1, 7, 1, 1, 1, 1, 1, 5, 1,
-        24, 1, 7, 1, 1, 1, 1, 1, 5, 1, 24, 13, 5, 1, 32, 1, 1, 1, 7, 1, 20, 13, 1, 1, 7, 1, 20, 1,
-        13, 1, 7, 1, 20, 1, 13, 9, 20, 1, 42, 1, 1, 9, 32, 1, 1, 1, 40, 1, 1, 1, 40, 1, 1, 1, 40,
-        1, 1, 1, 40, 1, 1, 1, 40, 1, 1, 1, 40, 1, 1, 1, 40, 11, 34, 1, 7, 1, 34, 1, 7, 1, 34, 1, 7,
-        1, 34, 11, 40, 1, 1, 1, 40, 1, 1, 1, 40, 1, 1, 1, 40, 13, 32, 1, 9, 1, 32, 1, 9, 1, 32, 1,
-        9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 1, 9, 1, 32, 11, 20,
-    ]
+    lib::solution::run_day(17, &Day17);
 }
@@ -0,0 +1,17 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_18::Day18;
+use lib::solution::Solution;
+
+const INPUT: &str = include_str!("../../fixtures/day18.txt");
+
+fn keys(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_18");
+    group.bench_function("parse", |b| b.iter(|| Day18.parse(black_box(INPUT))));
+    let graph = Day18.parse(INPUT).unwrap();
+    group.sample_size(10);
+    group.bench_function("part 1", |b| b.iter(|| Day18.part1(black_box(&graph))));
+    group.finish();
+}
+
+criterion_group!(benches, keys);
+criterion_main!(benches);
@@ -0,0 +1,230 @@
+// Inspiration taken from https://github.com/prscoelho/aoc2019/blob/master/src/aoc18/mod.rs
+
+use lib::geom::Point2;
+use lib::search::{self, Bfs, Dijkstra};
+use lib::solution::{Solution, Unsolved};
+use lib::{CharCell, Grid, ParseGridError};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Error, Formatter};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(&self, input: &str) -> Result<Graph, String> {
+        let graph = parse_graph(parse_grid(input)?);
+        if !graph.contains_key(&'@') {
+            return Err(String::from("The map has no entrance '@'"));
+        }
+        Ok(graph)
+    }
+
+    fn part1(&self, graph: &Graph) -> usize {
+        search(graph, '@')
+    }
+
+    fn part2(&self, _graph: &Graph) -> Unsolved {
+        Unsolved
+    }
+}
+
+type Graph = HashMap<char, HashMap<char, usize>>;
+
+/// The node we are at together with the keys collected so far.
+type State = (char, BTreeSet<char>);
+
+fn search(graph: &Graph, start: char) -> usize {
+    let n_keys = graph.keys().filter(|k| k.is_lowercase()).count();
+
+    let successors = |(node, keys): &State| {
+        search_reachable_keys_from_node(graph, keys, *node)
+            .into_iter()
+            .map(|(next_node, cost)| {
+                let mut next_keys = keys.clone();
+                next_keys.insert(next_node);
+                ((next_node, next_keys), cost)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    search::dijkstra((start, BTreeSet::new()), successors, |(_, keys)| {
+        keys.len() == n_keys
+    })
+    .map_or(usize::MAX, |(_, steps)| steps)
+}
+
+// Returns a list of keys that are reachable for a given node with their distances
+fn search_reachable_keys_from_node(
+    graph: &Graph,
+    keys: &BTreeSet<char>,
+    start: char,
+) -> Vec<(char, usize)> {
+    let is_new_key = |node: &char| node.is_lowercase() && !keys.contains(node);
+
+    // Stop at new keys and at doors we have no key for
+    let successors = |node: &char| {
+        let edges = if *node != start && is_new_key(node) {
+            vec![]
+        } else {
+            graph[node].iter().collect()
+        };
+        edges
+            .into_iter()
+            .filter(|(next_node, _)| {
+                !next_node.is_uppercase() || keys.contains(&next_node.to_ascii_lowercase())
+            })
+            .map(|(&next_node, &cost)| (next_node, cost))
+            .collect::<Vec<_>>()
+    };
+
+    Dijkstra::new(start, successors)
+        .filter(|(node, _)| is_new_key(node))
+        .collect()
+}
+
+fn parse_graph(grid: Grid<Tile>) -> Graph {
+    let mut result = HashMap::new();
+
+    for ((x, y), tile) in grid.iter() {
+        match tile {
+            Tile::Door(c) | Tile::Key(c) => {
+                let position = Position { x, y };
+                let reachable_nodes = find_reachable_nodes_from(&grid, position);
+                result.insert(*c, reachable_nodes);
+            }
+            _ => (),
+        }
+    }
+
+    result
+}
+
+fn find_reachable_nodes_from(grid: &Grid<Tile>, position: Position) -> HashMap<char, usize> {
+    let start = (position.x, position.y);
+
+    // Keys and doors end a path, everything beyond them is reached through their own edges
+    let successors = |&(x, y): &(isize, isize)| {
+        let open = (x, y) == start || matches!(grid.get(x, y), Some(Tile::Passage));
+        grid.neighbours4(x, y)
+            .filter(|(_, tile)| open && !matches!(tile, Tile::Wall))
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>()
+    };
+
+    Bfs::new(start, successors)
+        .skip(1)
+        .filter_map(|((x, y), steps)| match grid.get(x, y) {
+            Some(Tile::Key(c)) | Some(Tile::Door(c)) => Some((*c, steps)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_grid(input: &str) -> Result<Grid<Tile>, String> {
+    let lines = input.lines().map(str::trim).collect::<Vec<_>>();
+    lines
+        .join("\n")
+        .parse()
+        .map_err(|error: ParseGridError| error.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+enum Tile {
+    Wall,
+    #[default]
+    Passage,
+    Door(char),
+    Key(char),
+}
+
+impl CharCell for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Passage => '.',
+            Tile::Wall => '#',
+            Tile::Key(c) => *c,
+            Tile::Door(c) => *c,
+        }
+    }
+
+    fn from_char(char: char) -> Option<Tile> {
+        match char {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Passage),
+            c if c.is_ascii_uppercase() => Some(Tile::Door(c)),
+            c => Some(Tile::Key(c)),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+type Position = Point2<isize>;
+
+#[cfg(test)]
+mod tests {
+    use crate::Day18;
+    use lib::solution::Solution;
+
+    fn part1(input: &str) -> usize {
+        Day18.part1(&Day18.parse(input).unwrap())
+    }
+
+    #[test]
+    fn test_small_labyrinth() {
+        let input = "#########
+                            #b.A.@.a#
+                            #########";
+
+        let runner = part1(input);
+        assert_eq!(runner, 8);
+    }
+
+    #[test]
+    fn test_medium_labyrinth() {
+        let input = "\
+                ########################
+                #f.D.E.e.C.b.A.@.a.B.c.#
+                ######################.#
+                #d.....................#
+                ########################";
+        let result = part1(input);
+        assert_eq!(result, 86);
+    }
+
+    #[test]
+    fn test_medium_labyrinth2() {
+        let input = "\
+                #################
+                #i.G..c...e..H.p#
+                ########.########
+                #j.A..b...f..D.o#
+                ########@########
+                #k.E..a...g..B.n#
+                ########.########
+                #l.F..d...h..C.m#
+                #################";
+        let result = part1(input);
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn test_medium_labyrinth3() {
+        let input = "\
+                ########################
+                #@..............ac.GI.b#
+                ###d#e#f################
+                ###A#B#C################
+                ###g#h#i################
+                ########################";
+        let result = part1(input);
+        assert_eq!(result, 81);
+    }
+}
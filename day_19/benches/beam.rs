@@ -0,0 +1,20 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_19::Day19;
+use lib::solution::Solution;
+use std::time::Duration;
+
+const INPUT: &str = include_str!("../../fixtures/day19.txt");
+
+fn beam(c: &mut Criterion) {
+    let program = Day19.parse(INPUT).unwrap();
+    let mut group = c.benchmark_group("day_19");
+    group.sample_size(10);
+    group.bench_function("part 1", |b| b.iter(|| Day19.part1(black_box(&program))));
+    // A single run of the square search takes seconds.
+    group.measurement_time(Duration::from_secs(120));
+    group.bench_function("part 2", |b| b.iter(|| Day19.part2(black_box(&program))));
+    group.finish();
+}
+
+criterion_group!(benches, beam);
+criterion_main!(benches);
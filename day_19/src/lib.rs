@@ -0,0 +1,84 @@
+use intcode::{IntCodeComputer, Memory};
+use lib::solution::Solution;
+use lib::{Grid, Rect};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Memory;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> usize {
+        part1(program.clone())
+    }
+
+    fn part2(&self, program: &Memory) -> isize {
+        part2(program.clone())
+    }
+}
+
+fn produce_grid(input: Memory, width: usize, height: usize) -> Grid<char> {
+    let mut grid: Grid<char> = Grid::new(width, height, 0, 0);
+    let mut cpu = IntCodeComputer::new(input.clone());
+
+    for ((x, y), cell) in grid.iter_mut() {
+        cpu.reset(input.clone());
+        cpu.run(vec![x, y]);
+        let output = *cpu.get_output().first().unwrap();
+        *cell = tile_from_output(output);
+    }
+
+    grid
+}
+
+fn part1(input: Memory) -> usize {
+    let grid = produce_grid(input, 50, 50);
+    grid.positions_of(|c| c.eq(&'#')).count()
+}
+
+fn part2(input: Memory) -> isize {
+    let (width, height) = (1500, 1500);
+    let grid = produce_grid(input, width, height);
+    let (x, y) = grid
+        .positions_of(|c| c.eq(&'#'))
+        .find(|&(x, y)| can_fit(&grid, x, y))
+        .unwrap();
+    encode(x, y)
+}
+
+/// The answer the puzzle asks for, the ship's closest corner as `x * 10000 + y`.
+fn encode(x: isize, y: isize) -> isize {
+    x * 10000 + y
+}
+
+fn can_fit(grid: &Grid<char>, x: isize, y: isize) -> bool {
+    let ship = grid.subgrid(Rect::with_size((x, y), 100, 100));
+    let corners = [(x, y), (x + 99, y), (x, y + 99), (x + 99, y + 99)];
+    corners
+        .iter()
+        .all(|&(x, y)| ship.get(x, y).is_some_and(|cell| cell.eq(&'#')))
+}
+
+fn tile_from_output(output: isize) -> char {
+    match output {
+        0 => '.',
+        1 => '#',
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_encode() {
+        // The example's ship fits with its closest corner at 25,20
+        assert_eq!(encode(25, 20), 250020);
+    }
+}
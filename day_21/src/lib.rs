@@ -0,0 +1,95 @@
+use intcode::{IntCodeComputer, Memory, State};
+use lib::solution::Solution;
+use std::collections::VecDeque;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Memory;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Memory, String> {
+        lib::input::comma_separated(input)
+    }
+
+    fn part1(&self, program: &Memory) -> String {
+        let script = "\
+NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+WALK
+";
+        run_springscript(program.clone(), script)
+    }
+
+    fn part2(&self, program: &Memory) -> String {
+        // Jump over any hole in the next three tiles when there is ground to land on, and
+        // either ground right after landing or a second landing spot for the next jump
+        let script = "\
+NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+NOT E T
+NOT T T
+OR H T
+AND T J
+RUN
+";
+        run_springscript(program.clone(), script)
+    }
+}
+
+/// Runs the springdroid with `script` and returns the reported hull damage, or the drawing of
+/// its last moments if it fell into space.
+fn run_springscript(program: Memory, script: &str) -> String {
+    let mut cpu = IntCodeComputer::new(program);
+    let mut script = script.chars().map(|c| c as u8).collect::<VecDeque<u8>>();
+
+    let mut output: Vec<isize> = vec![];
+    loop {
+        match cpu.step() {
+            State::Output(o) => {
+                output.push(o);
+            }
+            State::WaitingForInput => {
+                let next = script.pop_front().unwrap();
+                cpu.read_input(next as isize);
+            }
+            State::Halt => break,
+            _ => (),
+        }
+    }
+
+    match output.last() {
+        // Hull damage is the only output outside of the ASCII range
+        Some(damage) if *damage > 127 => damage.to_string(),
+        _ => output.iter().map(|x| char::from(*x as u8)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day21;
+    use lib::solution::Solution;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../fixtures/day21.txt");
+        let program = Day21.parse(input).unwrap();
+        assert_eq!(Day21.part1(&program), "19358416");
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../fixtures/day21.txt");
+        let program = Day21.parse(input).unwrap();
+        assert_eq!(Day21.part2(&program), "1144641747");
+    }
+}
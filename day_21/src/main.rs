@@ -1,196 +1,5 @@
-use intcode::{IntCodeComputer, Memory, State};
-use std::collections::VecDeque;
+use day_21::Day21;
 
 fn main() {
-    part1(input());
-    // part2(input());
-}
-
-fn part1(input: Memory) {
-    let mut cpu = IntCodeComputer::new(input);
-
-    let mut program = "\
-NOT A J
-NOT B T
-OR T J
-NOT C T
-OR T J
-AND D J
-WALK
-"
-    .chars()
-    .map(|c| c as u8)
-    .collect::<VecDeque<u8>>();
-
-    let mut output: Vec<isize> = vec![];
-    loop {
-        match cpu.step() {
-            State::Output(o) => {
-                output.push(o);
-            }
-            State::WaitingForInput => {
-                let next = program.pop_front().unwrap();
-                cpu.read_input(next as isize);
-            }
-            State::Halt => {
-                let s = output
-                    .iter()
-                    .map(|x| char::from(*x as u8))
-                    .collect::<String>();
-                println!("{}", s);
-                println!("{:?}", output);
-                break;
-            }
-            _ => (),
-        }
-    }
-}
-
-fn part2(input: Memory) {
-    let mut cpu = IntCodeComputer::new(input);
-
-    let mut program = "\
-NOT B T
-NOT D J
-AND T J
-NOT A T
-AND D T
-OR T J
-RUN
-"
-    .chars()
-    .map(|c| c as u8)
-    .collect::<VecDeque<u8>>();
-
-    let mut output: Vec<isize> = vec![];
-    loop {
-        match cpu.step() {
-            State::Output(o) => {
-                output.push(o);
-            }
-            State::WaitingForInput => {
-                let next = program.pop_front().unwrap();
-                cpu.read_input(next as isize);
-            }
-            State::Halt => {
-                let s = output
-                    .iter()
-                    .map(|x| char::from(*x as u8))
-                    .collect::<String>();
-                println!("{}", s);
-                break;
-            }
-            _ => (),
-        }
-    }
-}
-
-fn input() -> Memory {
-    vec![
-        109, 2050, 21102, 1, 966, 1, 21101, 0, 13, 0, 1105, 1, 1378, 21101, 20, 0, 0, 1105, 1,
-        1337, 21102, 1, 27, 0, 1105, 1, 1279, 1208, 1, 65, 748, 1005, 748, 73, 1208, 1, 79, 748,
-        1005, 748, 110, 1208, 1, 78, 748, 1005, 748, 132, 1208, 1, 87, 748, 1005, 748, 169, 1208,
-        1, 82, 748, 1005, 748, 239, 21101, 1041, 0, 1, 21101, 73, 0, 0, 1105, 1, 1421, 21102, 78,
-        1, 1, 21101, 0, 1041, 2, 21102, 1, 88, 0, 1105, 1, 1301, 21101, 68, 0, 1, 21102, 1, 1041,
-        2, 21102, 103, 1, 0, 1105, 1, 1301, 1102, 1, 1, 750, 1106, 0, 298, 21101, 82, 0, 1, 21102,
-        1, 1041, 2, 21101, 125, 0, 0, 1105, 1, 1301, 1102, 1, 2, 750, 1106, 0, 298, 21102, 79, 1,
-        1, 21102, 1041, 1, 2, 21102, 1, 147, 0, 1106, 0, 1301, 21102, 84, 1, 1, 21101, 0, 1041, 2,
-        21101, 0, 162, 0, 1105, 1, 1301, 1101, 0, 3, 750, 1106, 0, 298, 21102, 1, 65, 1, 21102,
-        1041, 1, 2, 21102, 1, 184, 0, 1106, 0, 1301, 21102, 1, 76, 1, 21102, 1, 1041, 2, 21101,
-        199, 0, 0, 1106, 0, 1301, 21101, 0, 75, 1, 21101, 1041, 0, 2, 21102, 214, 1, 0, 1105, 1,
-        1301, 21102, 1, 221, 0, 1106, 0, 1337, 21101, 0, 10, 1, 21101, 1041, 0, 2, 21102, 236, 1,
-        0, 1106, 0, 1301, 1105, 1, 553, 21101, 85, 0, 1, 21101, 0, 1041, 2, 21101, 0, 254, 0, 1105,
-        1, 1301, 21101, 78, 0, 1, 21101, 1041, 0, 2, 21101, 0, 269, 0, 1105, 1, 1301, 21101, 276,
-        0, 0, 1106, 0, 1337, 21101, 10, 0, 1, 21101, 0, 1041, 2, 21102, 291, 1, 0, 1106, 0, 1301,
-        1102, 1, 1, 755, 1106, 0, 553, 21101, 0, 32, 1, 21102, 1, 1041, 2, 21102, 313, 1, 0, 1105,
-        1, 1301, 21101, 0, 320, 0, 1106, 0, 1337, 21102, 1, 327, 0, 1106, 0, 1279, 2101, 0, 1, 749,
-        21101, 65, 0, 2, 21101, 0, 73, 3, 21102, 1, 346, 0, 1105, 1, 1889, 1206, 1, 367, 1007, 749,
-        69, 748, 1005, 748, 360, 1101, 1, 0, 756, 1001, 749, -64, 751, 1106, 0, 406, 1008, 749, 74,
-        748, 1006, 748, 381, 1101, 0, -1, 751, 1106, 0, 406, 1008, 749, 84, 748, 1006, 748, 395,
-        1102, -2, 1, 751, 1105, 1, 406, 21101, 0, 1100, 1, 21101, 0, 406, 0, 1105, 1, 1421, 21101,
-        32, 0, 1, 21101, 0, 1100, 2, 21101, 0, 421, 0, 1106, 0, 1301, 21102, 1, 428, 0, 1106, 0,
-        1337, 21101, 435, 0, 0, 1105, 1, 1279, 1202, 1, 1, 749, 1008, 749, 74, 748, 1006, 748, 453,
-        1101, -1, 0, 752, 1105, 1, 478, 1008, 749, 84, 748, 1006, 748, 467, 1102, 1, -2, 752, 1105,
-        1, 478, 21101, 0, 1168, 1, 21101, 0, 478, 0, 1106, 0, 1421, 21101, 0, 485, 0, 1106, 0,
-        1337, 21102, 1, 10, 1, 21102, 1, 1168, 2, 21101, 0, 500, 0, 1105, 1, 1301, 1007, 920, 15,
-        748, 1005, 748, 518, 21101, 0, 1209, 1, 21101, 518, 0, 0, 1106, 0, 1421, 1002, 920, 3, 529,
-        1001, 529, 921, 529, 101, 0, 750, 0, 1001, 529, 1, 537, 101, 0, 751, 0, 1001, 537, 1, 545,
-        1001, 752, 0, 0, 1001, 920, 1, 920, 1105, 1, 13, 1005, 755, 577, 1006, 756, 570, 21101, 0,
-        1100, 1, 21101, 570, 0, 0, 1105, 1, 1421, 21101, 0, 987, 1, 1105, 1, 581, 21101, 1001, 0,
-        1, 21102, 588, 1, 0, 1105, 1, 1378, 1102, 758, 1, 593, 1002, 0, 1, 753, 1006, 753, 654,
-        20102, 1, 753, 1, 21102, 610, 1, 0, 1105, 1, 667, 21102, 0, 1, 1, 21101, 621, 0, 0, 1106,
-        0, 1463, 1205, 1, 647, 21101, 1015, 0, 1, 21101, 0, 635, 0, 1106, 0, 1378, 21102, 1, 1, 1,
-        21102, 1, 646, 0, 1105, 1, 1463, 99, 1001, 593, 1, 593, 1106, 0, 592, 1006, 755, 664, 1102,
-        1, 0, 755, 1105, 1, 647, 4, 754, 99, 109, 2, 1102, 726, 1, 757, 22102, 1, -1, 1, 21102, 1,
-        9, 2, 21101, 697, 0, 3, 21101, 692, 0, 0, 1106, 0, 1913, 109, -2, 2106, 0, 0, 109, 2, 1002,
-        757, 1, 706, 1201, -1, 0, 0, 1001, 757, 1, 757, 109, -2, 2105, 1, 0, 1, 1, 1, 1, 1, 1, 1,
-        1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 255, 63, 191, 127, 223, 95, 159, 0, 172, 61, 199, 218, 215, 249, 137, 254,
-        55, 102, 56, 38, 162, 177, 62, 245, 197, 220, 87, 226, 124, 110, 139, 188, 121, 158, 140,
-        241, 235, 125, 253, 107, 34, 119, 171, 186, 247, 230, 100, 233, 250, 243, 123, 221, 189,
-        116, 42, 54, 122, 200, 51, 175, 216, 76, 231, 50, 190, 185, 79, 109, 60, 43, 103, 228, 143,
-        117, 86, 239, 142, 187, 126, 182, 77, 222, 138, 71, 156, 167, 136, 251, 242, 154, 115, 246,
-        181, 59, 98, 166, 93, 114, 68, 157, 152, 155, 58, 118, 170, 205, 141, 252, 217, 163, 236,
-        92, 113, 168, 206, 244, 173, 196, 169, 69, 94, 237, 101, 248, 70, 99, 178, 53, 153, 207,
-        84, 47, 49, 108, 238, 85, 174, 106, 201, 111, 229, 120, 202, 35, 213, 212, 204, 57, 198,
-        227, 183, 232, 46, 234, 179, 214, 39, 203, 219, 78, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 20, 73, 110, 112, 117, 116, 32, 105, 110, 115, 116, 114, 117, 99, 116,
-        105, 111, 110, 115, 58, 10, 13, 10, 87, 97, 108, 107, 105, 110, 103, 46, 46, 46, 10, 10,
-        13, 10, 82, 117, 110, 110, 105, 110, 103, 46, 46, 46, 10, 10, 25, 10, 68, 105, 100, 110,
-        39, 116, 32, 109, 97, 107, 101, 32, 105, 116, 32, 97, 99, 114, 111, 115, 115, 58, 10, 10,
-        58, 73, 110, 118, 97, 108, 105, 100, 32, 111, 112, 101, 114, 97, 116, 105, 111, 110, 59,
-        32, 101, 120, 112, 101, 99, 116, 101, 100, 32, 115, 111, 109, 101, 116, 104, 105, 110, 103,
-        32, 108, 105, 107, 101, 32, 65, 78, 68, 44, 32, 79, 82, 44, 32, 111, 114, 32, 78, 79, 84,
-        67, 73, 110, 118, 97, 108, 105, 100, 32, 102, 105, 114, 115, 116, 32, 97, 114, 103, 117,
-        109, 101, 110, 116, 59, 32, 101, 120, 112, 101, 99, 116, 101, 100, 32, 115, 111, 109, 101,
-        116, 104, 105, 110, 103, 32, 108, 105, 107, 101, 32, 65, 44, 32, 66, 44, 32, 67, 44, 32,
-        68, 44, 32, 74, 44, 32, 111, 114, 32, 84, 40, 73, 110, 118, 97, 108, 105, 100, 32, 115,
-        101, 99, 111, 110, 100, 32, 97, 114, 103, 117, 109, 101, 110, 116, 59, 32, 101, 120, 112,
-        101, 99, 116, 101, 100, 32, 74, 32, 111, 114, 32, 84, 52, 79, 117, 116, 32, 111, 102, 32,
-        109, 101, 109, 111, 114, 121, 59, 32, 97, 116, 32, 109, 111, 115, 116, 32, 49, 53, 32, 105,
-        110, 115, 116, 114, 117, 99, 116, 105, 111, 110, 115, 32, 99, 97, 110, 32, 98, 101, 32,
-        115, 116, 111, 114, 101, 100, 0, 109, 1, 1005, 1262, 1270, 3, 1262, 20102, 1, 1262, 0, 109,
-        -1, 2106, 0, 0, 109, 1, 21101, 1288, 0, 0, 1106, 0, 1263, 21002, 1262, 1, 0, 1102, 1, 0,
-        1262, 109, -1, 2106, 0, 0, 109, 5, 21102, 1, 1310, 0, 1105, 1, 1279, 21202, 1, 1, -2,
-        22208, -2, -4, -1, 1205, -1, 1332, 22101, 0, -3, 1, 21102, 1, 1332, 0, 1105, 1, 1421, 109,
-        -5, 2106, 0, 0, 109, 2, 21102, 1, 1346, 0, 1105, 1, 1263, 21208, 1, 32, -1, 1205, -1, 1363,
-        21208, 1, 9, -1, 1205, -1, 1363, 1106, 0, 1373, 21102, 1, 1370, 0, 1106, 0, 1279, 1106, 0,
-        1339, 109, -2, 2105, 1, 0, 109, 5, 1201, -4, 0, 1385, 21002, 0, 1, -2, 22101, 1, -4, -4,
-        21101, 0, 0, -3, 22208, -3, -2, -1, 1205, -1, 1416, 2201, -4, -3, 1408, 4, 0, 21201, -3, 1,
-        -3, 1105, 1, 1396, 109, -5, 2106, 0, 0, 109, 2, 104, 10, 22101, 0, -1, 1, 21102, 1, 1436,
-        0, 1106, 0, 1378, 104, 10, 99, 109, -2, 2106, 0, 0, 109, 3, 20002, 593, 753, -1, 22202, -1,
-        -2, -1, 201, -1, 754, 754, 109, -3, 2106, 0, 0, 109, 10, 21102, 5, 1, -5, 21101, 1, 0, -4,
-        21102, 0, 1, -3, 1206, -9, 1555, 21101, 0, 3, -6, 21102, 1, 5, -7, 22208, -7, -5, -8, 1206,
-        -8, 1507, 22208, -6, -4, -8, 1206, -8, 1507, 104, 64, 1105, 1, 1529, 1205, -6, 1527, 1201,
-        -7, 716, 1515, 21002, 0, -11, -8, 21201, -8, 46, -8, 204, -8, 1106, 0, 1529, 104, 46,
-        21201, -7, 1, -7, 21207, -7, 22, -8, 1205, -8, 1488, 104, 10, 21201, -6, -1, -6, 21207, -6,
-        0, -8, 1206, -8, 1484, 104, 10, 21207, -4, 1, -8, 1206, -8, 1569, 21102, 0, 1, -9, 1106, 0,
-        1689, 21208, -5, 21, -8, 1206, -8, 1583, 21102, 1, 1, -9, 1106, 0, 1689, 1201, -5, 716,
-        1588, 21002, 0, 1, -2, 21208, -4, 1, -1, 22202, -2, -1, -1, 1205, -2, 1613, 21201, -5, 0,
-        1, 21102, 1613, 1, 0, 1106, 0, 1444, 1206, -1, 1634, 21202, -5, 1, 1, 21102, 1627, 1, 0,
-        1106, 0, 1694, 1206, 1, 1634, 21102, 1, 2, -3, 22107, 1, -4, -8, 22201, -1, -8, -8, 1206,
-        -8, 1649, 21201, -5, 1, -5, 1206, -3, 1663, 21201, -3, -1, -3, 21201, -4, 1, -4, 1106, 0,
-        1667, 21201, -4, -1, -4, 21208, -4, 0, -1, 1201, -5, 716, 1676, 22002, 0, -1, -1, 1206, -1,
-        1686, 21102, 1, 1, -4, 1106, 0, 1477, 109, -10, 2105, 1, 0, 109, 11, 21102, 1, 0, -6,
-        21102, 1, 0, -8, 21101, 0, 0, -7, 20208, -6, 920, -9, 1205, -9, 1880, 21202, -6, 3, -9,
-        1201, -9, 921, 1725, 20102, 1, 0, -5, 1001, 1725, 1, 1732, 21001, 0, 0, -4, 21202, -4, 1,
-        1, 21101, 0, 1, 2, 21102, 9, 1, 3, 21102, 1754, 1, 0, 1105, 1, 1889, 1206, 1, 1772, 2201,
-        -10, -4, 1766, 1001, 1766, 716, 1766, 21002, 0, 1, -3, 1105, 1, 1790, 21208, -4, -1, -9,
-        1206, -9, 1786, 22101, 0, -8, -3, 1105, 1, 1790, 21201, -7, 0, -3, 1001, 1732, 1, 1796,
-        20101, 0, 0, -2, 21208, -2, -1, -9, 1206, -9, 1812, 22102, 1, -8, -1, 1105, 1, 1816, 22102,
-        1, -7, -1, 21208, -5, 1, -9, 1205, -9, 1837, 21208, -5, 2, -9, 1205, -9, 1844, 21208, -3,
-        0, -1, 1105, 1, 1855, 22202, -3, -1, -1, 1106, 0, 1855, 22201, -3, -1, -1, 22107, 0, -1,
-        -1, 1106, 0, 1855, 21208, -2, -1, -9, 1206, -9, 1869, 22101, 0, -1, -8, 1106, 0, 1873,
-        21202, -1, 1, -7, 21201, -6, 1, -6, 1105, 1, 1708, 21201, -8, 0, -10, 109, -11, 2106, 0, 0,
-        109, 7, 22207, -6, -5, -3, 22207, -4, -6, -2, 22201, -3, -2, -1, 21208, -1, 0, -6, 109, -7,
-        2105, 1, 0, 0, 109, 5, 1202, -2, 1, 1912, 21207, -4, 0, -1, 1206, -1, 1930, 21102, 0, 1,
-        -4, 22102, 1, -4, 1, 22101, 0, -3, 2, 21102, 1, 1, 3, 21101, 1949, 0, 0, 1105, 1, 1954,
-        109, -5, 2106, 0, 0, 109, 6, 21207, -4, 1, -1, 1206, -1, 1977, 22207, -5, -3, -1, 1206, -1,
-        1977, 22102, 1, -5, -5, 1105, 1, 2045, 22101, 0, -5, 1, 21201, -4, -1, 2, 21202, -3, 2, 3,
-        21101, 1996, 0, 0, 1106, 0, 1954, 22102, 1, 1, -5, 21101, 1, 0, -2, 22207, -5, -3, -1,
-        1206, -1, 2015, 21101, 0, 0, -2, 22202, -3, -2, -3, 22107, 0, -4, -1, 1206, -1, 2037,
-        22102, 1, -2, 1, 21101, 2037, 0, 0, 105, 1, 1912, 21202, -3, -1, -3, 22201, -5, -3, -5,
-        109, -6, 2106, 0, 0,
-    ]
+    lib::solution::run_day(21, &Day21);
 }
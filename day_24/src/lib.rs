@@ -0,0 +1,282 @@
+use lib::automaton::{Automaton, Neighbourhood, Rule};
+use lib::solution::Solution;
+use lib::{CharCell, Grid, ParseGridError, Topology, NEIGHBOURS4};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<Tile>, String> {
+        parse_grid(input)
+    }
+
+    fn part1(&self, grid: &Grid<Tile>) -> usize {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<Tile>) -> usize {
+        part2(grid, 200)
+    }
+}
+
+fn part1(grid: &Grid<Tile>) -> usize {
+    lib::debug!(
+        "{}\nInitial Bio-Diversity: {}",
+        grid,
+        get_biodiversity_for_grid(grid)
+    );
+    let first_recurring = find_first_recurring(grid);
+    lib::debug!("First recurring:\n{}", first_recurring);
+    get_biodiversity_for_grid(&first_recurring)
+}
+
+fn part2(grid: &Grid<Tile>, minutes: usize) -> usize {
+    let mut levels = RecursiveGrid::new(grid.clone());
+    for _ in 0..minutes {
+        levels.step();
+    }
+    lib::debug!("Depth 0:\n{}", levels.render_level(0).unwrap());
+    levels.count_bugs()
+}
+
+/// The packed bits of a 5x5 grid are exactly its bio-diversity rating, larger grids would not
+/// fit into one word.
+fn get_biodiversity_for_grid(grid: &Grid<Tile>) -> usize {
+    assert_eq!(
+        (grid.width(), grid.height()),
+        (5, 5),
+        "Bio-diversity is only defined for 5x5 grids"
+    );
+    grid.to_bits(|tile| *tile == Tile::Bug).as_words()[0] as usize
+}
+
+fn find_first_recurring(grid: &Grid<Tile>) -> Grid<Tile> {
+    let mut bugs = automaton(grid);
+    bugs.find_cycle_by(|grid| grid.to_bits(|tile| *tile == Tile::Bug));
+    bugs.into_grid()
+}
+
+fn automaton(grid: &Grid<Tile>) -> Automaton<Bugs> {
+    let neighbourhood = Neighbourhood::Stencil(&NEIGHBOURS4, Topology::Bounded);
+    Automaton::new(grid.clone(), Bugs, neighbourhood)
+}
+
+/// A bug dies unless exactly one bug is adjacent, an empty tile gets infested by one or two.
+struct Bugs;
+
+impl Rule for Bugs {
+    type Cell = Tile;
+
+    fn is_live(&self, tile: &Tile) -> bool {
+        *tile == Tile::Bug
+    }
+
+    fn next(&self, tile: &Tile, n_bugs: usize) -> Tile {
+        match (tile, n_bugs) {
+            (Tile::Free, 1) | (Tile::Free, 2) | (Tile::Bug, 1) => Tile::Bug,
+            (Tile::Free, _) | (Tile::Bug, _) => Tile::Free,
+        }
+    }
+}
+
+const SIZE: isize = 5;
+const CENTRE: (isize, isize) = (2, 2);
+
+/// An infinite stack of 5x5 grids, where the centre tile of each level holds the next inner
+/// level. Levels are keyed by depth, the initial grid has depth 0 and inner levels are deeper.
+struct RecursiveGrid {
+    levels: BTreeMap<isize, Grid<Tile>>,
+}
+
+impl RecursiveGrid {
+    fn new(grid: Grid<Tile>) -> Self {
+        let mut levels = BTreeMap::new();
+        levels.insert(0, grid);
+        RecursiveGrid { levels }
+    }
+
+    fn step(&mut self) {
+        // Bugs can spread at most one level further per minute in either direction
+        let (&outermost, &innermost) = (
+            self.levels.keys().next().unwrap(),
+            self.levels.keys().next_back().unwrap(),
+        );
+        for (depth, new_depth) in [(outermost, outermost - 1), (innermost, innermost + 1)] {
+            if count_bugs(&self.levels[&depth]) > 0 {
+                let size = SIZE as usize;
+                self.levels.insert(new_depth, Grid::new(size, size, 0, 0));
+            }
+        }
+
+        let next_levels = self
+            .levels
+            .iter()
+            .map(|(&depth, grid)| {
+                let mut next = grid.clone();
+                for ((x, y), tile) in grid.iter() {
+                    if (x, y) != CENTRE {
+                        let n_bugs = self.count_adjacent_bugs(depth, x, y);
+                        next[(x, y)] = Bugs.next(tile, n_bugs);
+                    }
+                }
+                (depth, next)
+            })
+            .collect();
+        self.levels = next_levels;
+    }
+
+    /// Counts bugs next to `(x, y)` on the given level, on the surrounding level when at an
+    /// edge and on the inner level's facing edge when next to the centre.
+    fn count_adjacent_bugs(&self, depth: isize, x: isize, y: isize) -> usize {
+        NEIGHBOURS4
+            .iter()
+            .map(|(dx, dy)| {
+                let (nx, ny) = (x + dx, y + dy);
+                if (nx, ny) == CENTRE {
+                    let inner_edge = (0..SIZE).map(|i| match (dx, dy) {
+                        (1, 0) => (0, i),
+                        (-1, 0) => (SIZE - 1, i),
+                        (0, 1) => (i, 0),
+                        _ => (i, SIZE - 1),
+                    });
+                    inner_edge.filter(|&p| self.is_bug(depth + 1, p)).count()
+                } else if (0..SIZE).contains(&nx) && (0..SIZE).contains(&ny) {
+                    self.is_bug(depth, (nx, ny)) as usize
+                } else {
+                    let (cx, cy) = CENTRE;
+                    self.is_bug(depth - 1, (cx + dx, cy + dy)) as usize
+                }
+            })
+            .sum()
+    }
+
+    fn is_bug(&self, depth: isize, (x, y): (isize, isize)) -> bool {
+        self.levels
+            .get(&depth)
+            .and_then(|grid| grid.get(x, y))
+            .is_some_and(|tile| *tile == Tile::Bug)
+    }
+
+    fn count_bugs(&self) -> usize {
+        self.levels.values().map(count_bugs).sum()
+    }
+
+    /// Renders a single level, showing the centre tile as `?`.
+    fn render_level(&self, depth: isize) -> Option<String> {
+        let grid = self.levels.get(&depth)?;
+        let rows = grid.rows().enumerate().map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, tile)| match (x as isize, y as isize) {
+                    CENTRE => '?',
+                    _ => tile.to_char(),
+                })
+                .collect::<String>()
+        });
+        Some(rows.collect::<Vec<_>>().join("\n"))
+    }
+}
+
+fn count_bugs(grid: &Grid<Tile>) -> usize {
+    grid.positions_of(|tile| *tile == Tile::Bug).count()
+}
+
+fn parse_grid(input: &str) -> Result<Grid<Tile>, String> {
+    let grid: Grid<Tile> = input
+        .trim()
+        .parse()
+        .map_err(|error: ParseGridError| error.to_string())?;
+    if (grid.width(), grid.height()) != (5, 5) {
+        return Err(format!(
+            "Expected a 5x5 grid, found {}x{}",
+            grid.width(),
+            grid.height()
+        ));
+    }
+    Ok(grid)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub enum Tile {
+    Bug,
+    #[default]
+    Free,
+}
+
+impl CharCell for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Bug => '#',
+            Tile::Free => '.',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Tile::Bug),
+            '.' => Some(Tile::Free),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{automaton, get_biodiversity_for_grid, parse_grid, RecursiveGrid};
+
+    #[test]
+    fn test_biodiversity() {
+        let input = ".....\n.....\n.....\n#....\n.#...";
+        let grid = parse_grid(input).unwrap();
+        assert_eq!(get_biodiversity_for_grid(&grid), 2_129_920);
+    }
+
+    #[test]
+    fn test_parse_grid_round_trip() {
+        let input = "....#\n#..#.\n#..##\n..#..\n#....";
+        assert_eq!(parse_grid(input).unwrap().to_string(), input);
+        assert_eq!(
+            parse_grid("....\n...."),
+            Err(String::from("Expected a 5x5 grid, found 4x2"))
+        );
+    }
+
+    #[test]
+    fn test_calculate_next_grid() {
+        let grid = parse_grid("....#\n#..#.\n#..##\n..#..\n#....").unwrap();
+        let expected = parse_grid("#..#.\n####.\n###.#\n##.##\n.##..").unwrap();
+        let mut bugs = automaton(&grid);
+        bugs.step();
+        assert_eq!(bugs.grid().grid(), expected.grid());
+    }
+
+    #[test]
+    fn test_recursive_levels() {
+        let grid = parse_grid("....#\n#..#.\n#..##\n..#..\n#....").unwrap();
+        let mut levels = RecursiveGrid::new(grid);
+        for _ in 0..10 {
+            levels.step();
+        }
+        assert_eq!(levels.count_bugs(), 99);
+        assert_eq!(
+            levels.render_level(-5).unwrap(),
+            "..#..\n.#.#.\n..?.#\n.#.#.\n..#.."
+        );
+        assert_eq!(
+            levels.render_level(5).unwrap(),
+            "####.\n#..#.\n#.?#.\n####.\n....."
+        );
+        assert_eq!(levels.render_level(7), None);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{IntCodeComputer, IoDevice, MemoryValue, State};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+
+/// Holds at most one input value, outputs are handed out through `State::Output` instead.
+#[derive(Default)]
+struct Slot {
+    input: Option<MemoryValue>,
+}
+
+impl IoDevice for Slot {
+    fn input(&mut self) -> Option<MemoryValue> {
+        self.input.take()
+    }
+
+    fn output(&mut self, _value: MemoryValue) {}
+}
+
+impl IntCodeComputer {
+    /// Runs until the program halts, awaiting `input` whenever the program reads a value
+    /// and sending every output into `output`.
+    ///
+    /// Returns `State::WaitingForInput` if `input` ends before the program halts, so the
+    /// computer can be resumed with another stream later on.
+    pub async fn run_async<S, K>(&mut self, mut input: S, mut output: K) -> Result<State, K::Error>
+    where
+        S: Stream<Item = MemoryValue> + Unpin,
+        K: Sink<MemoryValue> + Unpin,
+    {
+        let mut slot = Slot::default();
+        loop {
+            match self.tick(&mut slot) {
+                State::Halt => return Ok(State::Halt),
+                State::Output(value) => output.send(value).await?,
+                State::WaitingForInput => match input.next().await {
+                    Some(value) => slot.input = Some(value),
+                    None => return Ok(State::WaitingForInput),
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{IntCodeComputer, Memory, State};
+    use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+    use futures::executor::LocalPool;
+    use futures::task::LocalSpawnExt;
+    use futures::StreamExt;
+
+    fn run_feedback_loop(program: Memory, phases: Vec<isize>) -> isize {
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        let channels = phases
+            .iter()
+            .map(|phase| {
+                let (sender, receiver) = unbounded();
+                sender.unbounded_send(*phase).unwrap();
+                (sender, receiver)
+            })
+            .collect::<Vec<_>>();
+        let (senders, mut receivers): (Vec<_>, Vec<UnboundedReceiver<isize>>) =
+            channels.into_iter().unzip();
+        senders[0].unbounded_send(0).unwrap();
+
+        // The last amplifier feeds back into the first one, we tap into that connection
+        // to remember the last thruster signal
+        let (last_sender, mut last_receiver) = unbounded();
+        let first_sender = senders[0].clone();
+        let outputs = senders
+            .into_iter()
+            .skip(1)
+            .chain(std::iter::once(last_sender))
+            .collect::<Vec<_>>();
+
+        for (input, output) in receivers.drain(..).zip(outputs) {
+            let mut amplifier = IntCodeComputer::new(program.clone());
+            spawner
+                .spawn_local(async move {
+                    let state = amplifier.run_async(input, output).await.unwrap();
+                    assert_eq!(state, State::Halt);
+                })
+                .unwrap();
+        }
+
+        let signal = pool.run_until(async move {
+            let mut last_signal = 0;
+            while let Some(signal) = last_receiver.next().await {
+                last_signal = signal;
+                // Once the first amplifier has halted, the feedback is simply dropped
+                let _ = first_sender.unbounded_send(signal);
+            }
+            last_signal
+        });
+        pool.run();
+        signal
+    }
+
+    #[test]
+    fn test_async_feedback_loop() {
+        let specs: Vec<(Memory, Vec<isize>, isize)> = vec![
+            (
+                vec![
+                    3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001,
+                    28, -1, 28, 1005, 28, 6, 99, 0, 0, 5,
+                ],
+                vec![9, 8, 7, 6, 5],
+                139629729,
+            ),
+            (
+                vec![
+                    3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55, 1005, 55, 26,
+                    1001, 54, -5, 54, 1105, 1, 12, 1, 53, 54, 53, 1008, 54, 0, 55, 1001, 55, 1, 55,
+                    2, 53, 55, 53, 4, 53, 1001, 56, -1, 56, 1005, 56, 6, 99, 0, 0, 0, 0, 10,
+                ],
+                vec![9, 7, 8, 5, 6],
+                18216,
+            ),
+        ];
+
+        for (program, phases, thruster_signal) in specs {
+            assert_eq!(run_feedback_loop(program, phases), thruster_signal);
+        }
+    }
+
+    #[test]
+    fn test_input_stream_ends() {
+        let program = vec![3, 20, 3, 21, 1, 20, 21, 22, 4, 22, 99];
+        let mut comp = IntCodeComputer::new(program);
+        let (sender, mut receiver) = unbounded();
+
+        let state = futures::executor::block_on(async {
+            let state = comp
+                .run_async(futures::stream::iter(vec![1]), sender.clone())
+                .await
+                .unwrap();
+            assert_eq!(state, State::WaitingForInput);
+            comp.run_async(futures::stream::iter(vec![2]), sender)
+                .await
+                .unwrap()
+        });
+
+        assert_eq!(state, State::Halt);
+        assert_eq!(futures::executor::block_on(receiver.next()), Some(3));
+    }
+}
@@ -0,0 +1,39 @@
+// Compiles the golden corpus programs, so the tests can replay them against the compiled
+// machines as well.
+
+use intcode::compiler;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=../corpus");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let mut index = String::new();
+
+    let mut entries = fs::read_dir("../corpus")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for path in entries {
+        let name = path.file_stem().unwrap().to_str().unwrap().to_owned();
+        let text = fs::read_to_string(&path).unwrap();
+        let program = text
+            .lines()
+            .find(|line| line.starts_with("program:"))
+            .unwrap()["program:".len()..]
+            .split(',')
+            .map(|v| v.trim().parse().unwrap())
+            .collect::<Vec<isize>>();
+
+        let module = Path::new(&out_dir).join(format!("{}.rs", name));
+        compiler::compile_to_file(&program, &module).unwrap();
+        writeln!(index, "pub mod {} {{ include!({:?}); }}", name, module).unwrap();
+    }
+
+    fs::write(Path::new(&out_dir).join("compiled_corpus.rs"), index).unwrap();
+}
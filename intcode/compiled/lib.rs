@@ -0,0 +1,5 @@
+//! The programs of the golden corpus, compiled by `build.rs` into one module per day.
+
+#![allow(clippy::all)]
+
+include!(concat!(env!("OUT_DIR"), "/compiled_corpus.rs"));
@@ -0,0 +1,404 @@
+//! Ahead-of-time translation of Intcode programs into Rust source.
+//!
+//! The generated module contains a `Machine` which runs the program as a `match pc` state
+//! machine over its own memory. Every compiled instruction checks that its cells still hold
+//! the original code, instructions which were modified in the meantime, jumps to addresses
+//! which were not compiled and all error cases are executed by the interpreter instead.
+//!
+//! This module only depends on `std`, so it can be pulled into build scripts:
+//!
+//! ```ignore
+//! // build.rs
+//! let out = Path::new(&env::var("OUT_DIR").unwrap()).join("program.rs");
+//! intcode::compiler::compile_to_file(&program, out).unwrap();
+//!
+//! // main.rs
+//! mod program {
+//!     include!(concat!(env!("OUT_DIR"), "/program.rs"));
+//! }
+//! ```
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Mirrors `MemoryValue`, spelled out so build scripts can include this file on its own
+type Value = isize;
+
+#[derive(Clone, Copy, Debug)]
+enum Param {
+    Position(Value),
+    Immediate(Value),
+    Relative(Value),
+}
+
+#[derive(Clone, Debug)]
+struct Instruction {
+    opcode: Value,
+    params: Vec<Param>,
+}
+
+impl Instruction {
+    fn decode(program: &[Value], address: usize) -> Option<Instruction> {
+        let raw = *program.get(address)?;
+        if raw < 0 {
+            return None;
+        }
+
+        let opcode = raw % 100;
+        let arity = match opcode {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            3 | 4 | 9 => 1,
+            99 => 0,
+            _ => return None,
+        };
+
+        let mut modes = raw / 100;
+        let mut params = vec![];
+        for n in 0..3 {
+            let mode = modes % 10;
+            modes /= 10;
+            if n >= arity {
+                if mode > 2 {
+                    return None;
+                }
+                continue;
+            }
+            let value = *program.get(address + n + 1)?;
+            params.push(match mode {
+                0 if value >= 0 => Param::Position(value),
+                1 => Param::Immediate(value),
+                2 => Param::Relative(value),
+                _ => return None,
+            });
+        }
+
+        // Writes to immediate parameters are left to the interpreter to report
+        let writes = match opcode {
+            1 | 2 | 7 | 8 => Some(params[2]),
+            3 => Some(params[0]),
+            _ => None,
+        };
+        if let Some(Param::Immediate(_)) = writes {
+            return None;
+        }
+
+        Some(Instruction { opcode, params })
+    }
+
+    fn size(&self) -> usize {
+        self.params.len() + 1
+    }
+
+    /// Addresses execution may continue at after this instruction.
+    fn successors(&self, address: usize) -> Vec<Value> {
+        let mut successors = vec![];
+        if self.opcode != 99 {
+            successors.push((address + self.size()) as Value);
+        }
+        // Immediate values are often return addresses pushed onto the stack
+        for param in &self.params {
+            if let Param::Immediate(value) = param {
+                successors.push(*value);
+            }
+        }
+        successors
+    }
+}
+
+/// Decodes every instruction reachable from address 0, following the control flow and any
+/// immediate value which might be used as a jump target later on.
+fn decode_reachable(program: &[Value]) -> BTreeMap<usize, Instruction> {
+    let mut instructions = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(0);
+
+    while let Some(address) = queue.pop_front() {
+        if address < 0 || address as usize >= program.len() {
+            continue;
+        }
+        let address = address as usize;
+        if instructions.contains_key(&address) {
+            continue;
+        }
+        if let Some(instruction) = Instruction::decode(program, address) {
+            queue.extend(instruction.successors(address));
+            instructions.insert(address, instruction);
+        }
+    }
+
+    instructions
+}
+
+fn read(param: &Param) -> String {
+    match param {
+        Param::Immediate(value) => format!("{}", value),
+        Param::Position(address) => format!(
+            "match self.read({}) {{ Some(v) => v, None => break 'compiled }}",
+            address
+        ),
+        Param::Relative(offset) => format!(
+            "match self.relative({}).and_then(|a| self.read(a)) {{ Some(v) => v, None => break 'compiled }}",
+            offset
+        ),
+    }
+}
+
+fn target(param: &Param) -> String {
+    let address = match param {
+        Param::Position(address) => format!("Some({})", address),
+        Param::Relative(offset) => format!("self.relative({})", offset),
+        Param::Immediate(_) => unreachable!(),
+    };
+    format!(
+        "match {}.and_then(|a| self.target(a)) {{ Some(a) => a, None => break 'compiled }}",
+        address
+    )
+}
+
+fn emit_instruction(
+    out: &mut String,
+    program: &[Value],
+    address: usize,
+    instruction: &Instruction,
+) {
+    let size = instruction.size();
+    let next = address + size;
+    let code = program[address..next]
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let p = &instruction.params;
+
+    writeln!(out, "                {} => 'compiled: {{", address).unwrap();
+    writeln!(
+        out,
+        "                    if self.memory.get({}..{}) != Some(&[{}][..]) {{ break 'compiled; }}",
+        address, next, code
+    )
+    .unwrap();
+
+    let body = match instruction.opcode {
+        1 | 2 | 7 | 8 => {
+            let value = match instruction.opcode {
+                1 => "match a.checked_add(b) { Some(v) => v, None => break 'compiled }",
+                2 => "match a.checked_mul(b) { Some(v) => v, None => break 'compiled }",
+                7 => "(a < b) as MemoryValue",
+                _ => "(a == b) as MemoryValue",
+            };
+            format!(
+                "let a: MemoryValue = {};\nlet b: MemoryValue = {};\nlet target = {};\nself.memory[target] = {};\nself.pc = {};\ncontinue 'run;",
+                read(&p[0]),
+                read(&p[1]),
+                target(&p[2]),
+                value,
+                next
+            )
+        }
+        3 => format!(
+            "let target = {};\nmatch io.input() {{\n    Some(v) => self.memory[target] = v,\n    None => return Ok(State::WaitingForInput),\n}}\nself.pc = {};\ncontinue 'run;",
+            target(&p[0]),
+            next
+        ),
+        4 => format!(
+            "let a: MemoryValue = {};\nio.output(a);\nself.pc = {};\ncontinue 'run;",
+            read(&p[0]),
+            next
+        ),
+        5 | 6 => format!(
+            "let a: MemoryValue = {};\nlet b: MemoryValue = {};\nif {} {{\n    if b < 0 {{ break 'compiled; }}\n    self.pc = b as usize;\n}} else {{\n    self.pc = {};\n}}\ncontinue 'run;",
+            read(&p[0]),
+            read(&p[1]),
+            if instruction.opcode == 5 { "a != 0" } else { "a == 0" },
+            next
+        ),
+        9 => format!(
+            "let a: MemoryValue = {};\nself.relative_base = match self.relative_base.checked_add(a) {{ Some(v) => v, None => break 'compiled }};\nself.pc = {};\ncontinue 'run;",
+            read(&p[0]),
+            next
+        ),
+        _ => String::from("return Ok(State::Halt);"),
+    };
+
+    for line in body.lines() {
+        writeln!(out, "                    {}", line).unwrap();
+    }
+    writeln!(out, "                }}").unwrap();
+}
+
+/// Translates `program` into the source of a Rust module, see the module documentation.
+pub fn compile(program: &[Value]) -> String {
+    let instructions = decode_reachable(program);
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// Generated by intcode::compiler from a program of {} values, do not edit.",
+        program.len()
+    )
+    .unwrap();
+    out.push_str(
+        "
+use intcode::{IntCodeComputer, IntCodeError, IoDevice, Memory, MemoryValue, State};
+
+",
+    );
+    writeln!(
+        out,
+        "pub const PROGRAM: [MemoryValue; {}] = {:?};",
+        program.len(),
+        program
+    )
+    .unwrap();
+    out.push_str(
+        "
+#[derive(Clone, Debug)]
+pub struct Machine {
+    memory: Memory,
+    pc: usize,
+    relative_base: MemoryValue,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Machine::new()
+    }
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Machine::with_program(PROGRAM.to_vec())
+    }
+
+    /// Runs a modified copy of `PROGRAM`, with the same memory layout as `IntCodeComputer::new`.
+    pub fn with_program(program: Memory) -> Self {
+        let mut memory = program;
+        memory.extend(std::iter::repeat(0).take(memory.len() * 10));
+        Machine {
+            memory,
+            pc: 0,
+            relative_base: 0,
+        }
+    }
+
+    pub fn memory(&self) -> &[MemoryValue] {
+        &self.memory
+    }
+
+    fn read(&self, address: MemoryValue) -> Option<MemoryValue> {
+        self.memory.get(address as usize).copied()
+    }
+
+    fn target(&self, address: MemoryValue) -> Option<usize> {
+        Some(address as usize).filter(|a| *a < self.memory.len())
+    }
+
+    #[allow(dead_code)]
+    fn relative(&self, offset: MemoryValue) -> Option<MemoryValue> {
+        self.relative_base.checked_add(offset).filter(|a| *a >= 0)
+    }
+
+    fn interpret<D: IoDevice + ?Sized>(&mut self, io: &mut D) -> Result<State, IntCodeError> {
+        let memory = std::mem::take(&mut self.memory);
+        let mut cpu = IntCodeComputer::from_state(memory, self.pc, self.relative_base);
+        let state = cpu.try_step_with(io);
+        let (memory, pc, relative_base) = cpu.into_state();
+        self.memory = memory;
+        self.pc = pc;
+        self.relative_base = relative_base;
+        state
+    }
+
+    /// Runs until the program halts or `io` has no more input to offer, like
+    /// `IntCodeComputer::try_run_with`.
+    pub fn run<D: IoDevice + ?Sized>(&mut self, io: &mut D) -> Result<State, IntCodeError> {
+        loop {
+            match self.run_steps(io, usize::MAX)? {
+                State::Running => continue,
+                state => return Ok(state),
+            }
+        }
+    }
+
+    /// Like `run`, but returns `State::Running` after executing `steps` instructions.
+    pub fn run_steps<D: IoDevice + ?Sized>(
+        &mut self,
+        io: &mut D,
+        mut steps: usize,
+    ) -> Result<State, IntCodeError> {
+        'run: loop {
+            if steps == 0 {
+                return Ok(State::Running);
+            }
+            steps -= 1;
+
+            match self.pc {
+",
+    );
+
+    for (address, instruction) in &instructions {
+        emit_instruction(&mut out, program, *address, instruction);
+    }
+
+    out.push_str(
+        "                _ => (),
+            }
+
+            match self.interpret(io)? {
+                State::Halt => return Ok(State::Halt),
+                State::WaitingForInput => return Ok(State::WaitingForInput),
+                _ => (),
+            }
+        }
+    }
+}
+",
+    );
+
+    out
+}
+
+/// Compiles `program` and writes the module to `path`, only touching the file if it changed.
+pub fn compile_to_file<P: AsRef<Path>>(program: &[Value], path: P) -> io::Result<()> {
+    let source = compile(program);
+    if fs::read_to_string(path.as_ref()).ok().as_ref() == Some(&source) {
+        return Ok(());
+    }
+    fs::write(path, source)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_reachable() {
+        // Skips the data at 3 and 4, immediates 1 and 9 are potential entry points
+        let program = vec![1105, 1, 5, 42, 42, 1101, 9, 0, 20, 99];
+        let addresses = decode_reachable(&program)
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(addresses, vec![0, 1, 5, 9]);
+
+        assert!(Instruction::decode(&[1101, 1, 1, 20], 0).is_some());
+        assert!(Instruction::decode(&[11101, 1, 1, 20], 0).is_none());
+        assert!(Instruction::decode(&[1, -1, 1, 20], 0).is_none());
+        assert!(Instruction::decode(&[1, 1, 1], 0).is_none());
+        assert!(Instruction::decode(&[42], 0).is_none());
+    }
+
+    #[test]
+    fn test_compile() {
+        let source = compile(&[3, 0, 4, 0, 99]);
+        assert!(source.contains("pub const PROGRAM: [MemoryValue; 5] = [3, 0, 4, 0, 99];"));
+        assert!(source.contains("0 => 'compiled: {"));
+        assert!(source.contains("2 => 'compiled: {"));
+        assert!(source.contains("4 => 'compiled: {"));
+        assert!(source.contains("pub fn run_steps<D: IoDevice + ?Sized>("));
+    }
+}
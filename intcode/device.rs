@@ -0,0 +1,227 @@
+use crate::{Memory, MemoryValue};
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Stdin, Stdout, Write};
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Anything an `IntCodeComputer` can read its input from and write its output to.
+///
+/// Returning `None` from `input` suspends the computer with `State::WaitingForInput`,
+/// so it can be resumed later once more input is available.
+pub trait IoDevice {
+    fn input(&mut self) -> Option<MemoryValue>;
+    fn output(&mut self, value: MemoryValue);
+}
+
+impl<D: IoDevice + ?Sized> IoDevice for &mut D {
+    fn input(&mut self) -> Option<MemoryValue> {
+        (**self).input()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        (**self).output(value)
+    }
+}
+
+impl<D: IoDevice + ?Sized> IoDevice for Box<D> {
+    fn input(&mut self) -> Option<MemoryValue> {
+        (**self).input()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        (**self).output(value)
+    }
+}
+
+/// Stack-like buffers, inputs are popped from the back of `input`.
+///
+/// This is what `IntCodeComputer::run` and `IntCodeComputer::read_input` use internally.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VecDevice {
+    pub input: Memory,
+    pub output: Memory,
+}
+
+impl VecDevice {
+    pub fn new(input: Memory) -> Self {
+        VecDevice {
+            input,
+            output: vec![],
+        }
+    }
+}
+
+impl IoDevice for VecDevice {
+    fn input(&mut self) -> Option<MemoryValue> {
+        self.input.pop()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        self.output.push(value);
+    }
+}
+
+/// First-in-first-out buffers, inputs are read in the order they were pushed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueueDevice {
+    pub input: VecDeque<MemoryValue>,
+    pub output: Memory,
+}
+
+impl QueueDevice {
+    pub fn new<I: IntoIterator<Item = MemoryValue>>(input: I) -> Self {
+        QueueDevice {
+            input: input.into_iter().collect(),
+            output: vec![],
+        }
+    }
+
+    pub fn push(&mut self, value: MemoryValue) {
+        self.input.push_back(value);
+    }
+}
+
+impl IoDevice for QueueDevice {
+    fn input(&mut self) -> Option<MemoryValue> {
+        self.input.pop_front()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        self.output.push(value);
+    }
+}
+
+/// Forwards input requests and outputs to a pair of closures.
+pub struct FnDevice<I, O> {
+    input: I,
+    output: O,
+}
+
+impl<I, O> FnDevice<I, O>
+where
+    I: FnMut() -> Option<MemoryValue>,
+    O: FnMut(MemoryValue),
+{
+    pub fn new(input: I, output: O) -> Self {
+        FnDevice { input, output }
+    }
+}
+
+impl<I, O> IoDevice for FnDevice<I, O>
+where
+    I: FnMut() -> Option<MemoryValue>,
+    O: FnMut(MemoryValue),
+{
+    fn input(&mut self) -> Option<MemoryValue> {
+        (self.input)()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        (self.output)(value)
+    }
+}
+
+/// Connects a computer to other threads. Reading input blocks until a value arrives and
+/// yields `None` once all senders are gone. Outputs sent to a dropped receiver are discarded.
+pub struct ChannelDevice {
+    receiver: Receiver<MemoryValue>,
+    sender: Sender<MemoryValue>,
+}
+
+impl ChannelDevice {
+    pub fn new(receiver: Receiver<MemoryValue>, sender: Sender<MemoryValue>) -> Self {
+        ChannelDevice { receiver, sender }
+    }
+}
+
+impl IoDevice for ChannelDevice {
+    fn input(&mut self) -> Option<MemoryValue> {
+        self.receiver.recv().ok()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        let _ = self.sender.send(value);
+    }
+}
+
+/// Reads input line by line as ASCII and prints ASCII output as characters.
+/// Values outside of the ASCII range are printed as numbers on their own line.
+pub struct AsciiDevice<R, W> {
+    reader: R,
+    writer: W,
+    pending: VecDeque<MemoryValue>,
+}
+
+impl AsciiDevice<BufReader<Stdin>, Stdout> {
+    pub fn stdio() -> Self {
+        AsciiDevice::new(BufReader::new(io::stdin()), io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> AsciiDevice<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        AsciiDevice {
+            reader,
+            writer,
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<R: BufRead, W: Write> IoDevice for AsciiDevice<R, W> {
+    fn input(&mut self) -> Option<MemoryValue> {
+        if self.pending.is_empty() {
+            self.writer.flush().ok()?;
+            let mut line = String::new();
+            if self.reader.read_line(&mut line).ok()? == 0 {
+                return None;
+            }
+            self.pending.extend(line.bytes().map(|b| b as MemoryValue));
+        }
+        self.pending.pop_front()
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        let result = match value {
+            0..=127 => self.writer.write_all(&[value as u8]),
+            _ => writeln!(self.writer, "{}", value),
+        };
+        result.expect("Failed to write output");
+    }
+}
+
+/// Wraps another device and records every value passing through it.
+#[derive(Clone, Debug, Default)]
+pub struct TeeDevice<D> {
+    pub inner: D,
+    pub inputs: Memory,
+    pub outputs: Memory,
+}
+
+impl<D: IoDevice> TeeDevice<D> {
+    pub fn new(inner: D) -> Self {
+        TeeDevice {
+            inner,
+            inputs: vec![],
+            outputs: vec![],
+        }
+    }
+}
+
+impl<D: IoDevice> IoDevice for TeeDevice<D> {
+    fn input(&mut self) -> Option<MemoryValue> {
+        let value = self.inner.input();
+        if let Some(v) = value {
+            self.inputs.push(v);
+        }
+        value
+    }
+
+    fn output(&mut self, value: MemoryValue) {
+        self.outputs.push(value);
+        self.inner.output(value);
+    }
+}
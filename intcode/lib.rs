@@ -1,4 +1,14 @@
 use crate::Parameter::Reference;
+use std::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "async")]
+mod asynchronous;
+pub mod compiler;
+mod device;
+
+pub use crate::device::{
+    AsciiDevice, ChannelDevice, FnDevice, IoDevice, QueueDevice, TeeDevice, VecDevice,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Parameter {
@@ -7,11 +17,15 @@ pub enum Parameter {
 }
 
 impl Parameter {
-    fn eval(&self, memory: &Memory) -> MemoryValue {
-        //        println!("{:?}", self);
+    fn eval(&self, memory: &Memory, pc: MemoryIndex) -> Result<MemoryValue, IntCodeError> {
         match self {
-            Parameter::Value { value: v } => v.clone(),
-            Parameter::Reference { address: a } => memory.get(*a).unwrap().clone(),
+            Parameter::Value { value: v } => Ok(*v),
+            Parameter::Reference { address: a } => {
+                memory.get(*a).copied().ok_or(IntCodeError::InvalidAddress {
+                    pc,
+                    address: *a as MemoryValue,
+                })
+            }
         }
     }
 }
@@ -52,6 +66,44 @@ pub type MemoryIndex = usize;
 pub type Memory = Vec<MemoryValue>;
 pub type Output = Vec<String>;
 
+/// Reasons a program can fail, each carrying the address of the failing instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IntCodeError {
+    InvalidInstruction {
+        pc: MemoryIndex,
+        instruction: MemoryValue,
+    },
+    InvalidAddress {
+        pc: MemoryIndex,
+        address: MemoryValue,
+    },
+    WriteToImmediate {
+        pc: MemoryIndex,
+    },
+    Overflow {
+        pc: MemoryIndex,
+    },
+}
+
+impl Display for IntCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IntCodeError::InvalidInstruction { pc, instruction } => {
+                write!(f, "Invalid instruction {} at {}", instruction, pc)
+            }
+            IntCodeError::InvalidAddress { pc, address } => {
+                write!(f, "Invalid address {} accessed at {}", address, pc)
+            }
+            IntCodeError::WriteToImmediate { pc } => {
+                write!(f, "Write to immediate parameter at {}", pc)
+            }
+            IntCodeError::Overflow { pc } => write!(f, "Arithmetic overflow at {}", pc),
+        }
+    }
+}
+
+impl std::error::Error for IntCodeError {}
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 enum Mode {
     Position,
@@ -60,12 +112,12 @@ enum Mode {
 }
 
 impl Mode {
-    fn from_isize(input: MemoryValue) -> Self {
+    fn from_isize(input: MemoryValue) -> Option<Self> {
         match input {
-            0 => Mode::Position,
-            1 => Mode::Immediate,
-            2 => Mode::Relative,
-            _ => unreachable!(),
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
         }
     }
 }
@@ -75,19 +127,16 @@ type ModeSet = (Mode, Mode, Mode);
 #[derive(Debug, Clone)]
 pub struct IntCodeComputer {
     memory: Memory,
-    input: Memory,
-    output: Memory,
+    io: VecDevice,
     pc: MemoryIndex,
-    relative_base: MemoryIndex,
+    relative_base: MemoryValue,
 }
 
 impl IntCodeComputer {
     pub fn new(memory: Memory) -> Self {
-        let len = memory.len();
         let mut cpu = IntCodeComputer {
             memory: vec![],
-            input: vec![],
-            output: vec![],
+            io: VecDevice::default(),
             pc: 0,
             relative_base: 0,
         };
@@ -95,10 +144,25 @@ impl IntCodeComputer {
         cpu
     }
 
+    /// Resumes execution of a memory image, e.g. one handed over from a compiled program.
+    /// Unlike `new`, `memory` is used as is.
+    pub fn from_state(memory: Memory, pc: MemoryIndex, relative_base: MemoryValue) -> Self {
+        IntCodeComputer {
+            memory,
+            io: VecDevice::default(),
+            pc,
+            relative_base,
+        }
+    }
+
+    /// Gives up the memory image along with the program counter and relative base.
+    pub fn into_state(self) -> (Memory, MemoryIndex, MemoryValue) {
+        (self.memory, self.pc, self.relative_base)
+    }
+
     pub fn reset(&mut self, memory: Memory) {
         self.set_memory(memory);
-        self.output.clear();
-        self.input.clear();
+        self.io = VecDevice::default();
         self.pc = 0;
         self.relative_base = 0;
     }
@@ -106,8 +170,7 @@ impl IntCodeComputer {
     fn set_memory(&mut self, memory: Memory) {
         let memory_length = memory.len() * 10;
         self.memory = memory;
-        self.memory
-            .extend((0..memory_length).into_iter().map(|_| 0));
+        self.memory.extend((0..memory_length).map(|_| 0));
     }
 
     pub fn get_memory(&self) -> Memory {
@@ -115,205 +178,298 @@ impl IntCodeComputer {
     }
 
     pub fn get_output(&self) -> Memory {
-        self.output.clone()
+        self.io.output.clone()
     }
 
-    fn tick(&mut self) -> State {
-        let operation = self.next_operation();
-        let new_state = self.execute_command(&operation);
+    fn tick<D: IoDevice + ?Sized>(&mut self, io: &mut D) -> State {
+        self.try_tick(io).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn try_tick<D: IoDevice + ?Sized>(&mut self, io: &mut D) -> Result<State, IntCodeError> {
+        let operation = self.next_operation()?;
+        let new_state = self.execute_command(&operation, io)?;
 
         match new_state {
             State::Jump | State::Halt | State::WaitingForInput => (),
             _ => self.pc += operation.size() as usize,
         }
 
-        new_state
+        Ok(new_state)
     }
 
-    fn next_operation(&self) -> Operation {
+    fn next_operation(&self) -> Result<Operation, IntCodeError> {
         let position = self.pc;
-        let raw_op_code = self.memory[position];
-        let (op_code, mode_set) = Self::decode_opcode(raw_op_code);
-
-        match op_code {
+        let raw_op_code = self.get_parameter(position)?;
+        let (op_code, mode_set) =
+            Self::decode_opcode(raw_op_code).ok_or(IntCodeError::InvalidInstruction {
+                pc: position,
+                instruction: raw_op_code,
+            })?;
+
+        let operation = match op_code {
             1 => Operation::Add(
-                self.get_parameter_for_mode(position + 1, mode_set.0),
-                self.get_parameter_for_mode(position + 2, mode_set.1),
-                self.get_parameter_for_mode(position + 3, mode_set.2),
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
+                self.get_parameter_for_mode(position + 2, mode_set.1)?,
+                self.get_parameter_for_mode(position + 3, mode_set.2)?,
             ),
             2 => Operation::Mul(
-                self.get_parameter_for_mode(position + 1, mode_set.0),
-                self.get_parameter_for_mode(position + 2, mode_set.1),
-                self.get_parameter_for_mode(position + 3, mode_set.2),
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
+                self.get_parameter_for_mode(position + 2, mode_set.1)?,
+                self.get_parameter_for_mode(position + 3, mode_set.2)?,
             ),
-            3 => Operation::Input(self.get_parameter_for_mode(position + 1, mode_set.0)),
-            4 => Operation::Output(self.get_parameter_for_mode(position + 1, mode_set.0)),
+            3 => Operation::Input(self.get_parameter_for_mode(position + 1, mode_set.0)?),
+            4 => Operation::Output(self.get_parameter_for_mode(position + 1, mode_set.0)?),
             5 => Operation::JumpTrue(
-                self.get_parameter_for_mode(position + 1, mode_set.0),
-                self.get_parameter_for_mode(position + 2, mode_set.1),
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
+                self.get_parameter_for_mode(position + 2, mode_set.1)?,
             ),
             6 => Operation::JumpFalse(
-                self.get_parameter_for_mode(position + 1, mode_set.0),
-                self.get_parameter_for_mode(position + 2, mode_set.1),
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
+                self.get_parameter_for_mode(position + 2, mode_set.1)?,
             ),
             7 => Operation::LessThan(
-                self.get_parameter_for_mode(position + 1, mode_set.0),
-                self.get_parameter_for_mode(position + 2, mode_set.1),
-                self.get_parameter_for_mode(position + 3, mode_set.2),
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
+                self.get_parameter_for_mode(position + 2, mode_set.1)?,
+                self.get_parameter_for_mode(position + 3, mode_set.2)?,
             ),
             8 => Operation::Equal(
-                self.get_parameter_for_mode(position + 1, mode_set.0),
-                self.get_parameter_for_mode(position + 2, mode_set.1),
-                self.get_parameter_for_mode(position + 3, mode_set.2),
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
+                self.get_parameter_for_mode(position + 2, mode_set.1)?,
+                self.get_parameter_for_mode(position + 3, mode_set.2)?,
+            ),
+            9 => Operation::AdjustRelativeBase(
+                self.get_parameter_for_mode(position + 1, mode_set.0)?,
             ),
-            9 => {
-                Operation::AdjustRelativeBase(self.get_parameter_for_mode(position + 1, mode_set.0))
-            }
             99 => Operation::Halt,
-            _ => unreachable!(),
-        }
+            _ => {
+                return Err(IntCodeError::InvalidInstruction {
+                    pc: position,
+                    instruction: raw_op_code,
+                })
+            }
+        };
+
+        Ok(operation)
     }
 
-    fn decode_opcode(input: MemoryValue) -> (u32, ModeSet) {
+    fn decode_opcode(input: MemoryValue) -> Option<(u32, ModeSet)> {
+        if input < 0 {
+            return None;
+        }
+
         let opcode = input % 100;
         let c = (input / 10_000) % 10;
         let b = (input / 1_000) % 10;
         let a = (input / 100) % 10;
 
-        let mode_a = Mode::from_isize(a);
-        let mode_b = Mode::from_isize(b);
-        let mode_c = Mode::from_isize(c);
+        let mode_a = Mode::from_isize(a)?;
+        let mode_b = Mode::from_isize(b)?;
+        let mode_c = Mode::from_isize(c)?;
 
-        (opcode as u32, (mode_a, mode_b, mode_c))
+        Some((opcode as u32, (mode_a, mode_b, mode_c)))
     }
 
-    fn get_parameter_for_mode(&self, index: MemoryIndex, mode: Mode) -> Parameter {
-        match mode {
-            Mode::Position => Self::get_reference(&self, index),
-            Mode::Immediate => Self::get_value(&self, index),
-            Mode::Relative => {
-                let value_at_index = Self::get_parameter(&self, index);
-                let relative_reference_address =
-                    (self.relative_base as isize + value_at_index) as usize;
-                Reference {
-                    address: relative_reference_address,
-                }
+    fn get_parameter_for_mode(
+        &self,
+        index: MemoryIndex,
+        mode: Mode,
+    ) -> Result<Parameter, IntCodeError> {
+        let value_at_index = self.get_parameter(index)?;
+        let address = match mode {
+            Mode::Immediate => {
+                return Ok(Parameter::Value {
+                    value: value_at_index,
+                })
             }
+            Mode::Position => value_at_index,
+            Mode::Relative => self
+                .relative_base
+                .checked_add(value_at_index)
+                .ok_or(IntCodeError::Overflow { pc: self.pc })?,
+        };
+
+        if address < 0 {
+            return Err(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address,
+            });
         }
+
+        Ok(Reference {
+            address: address as MemoryIndex,
+        })
     }
 
-    fn get_parameter(&self, index: MemoryIndex) -> MemoryValue {
-        self.memory.get(index as usize).unwrap().clone()
+    fn get_parameter(&self, index: MemoryIndex) -> Result<MemoryValue, IntCodeError> {
+        self.memory
+            .get(index)
+            .copied()
+            .ok_or(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address: index as MemoryValue,
+            })
     }
 
-    fn get_reference(&self, index: MemoryIndex) -> Parameter {
-        Parameter::Reference {
-            address: self.get_parameter(index) as usize,
-        }
+    pub fn run(&mut self, input: Memory) -> Output {
+        self.io.input = input;
+        let mut io = std::mem::take(&mut self.io);
+        while self.tick(&mut io) != State::Halt {}
+        self.io = io;
+
+        self.io.output.iter().map(|x| format!("{:?}", x)).collect()
+    }
+
+    pub fn step(&mut self) -> State {
+        let mut io = std::mem::take(&mut self.io);
+        let state = self.tick(&mut io);
+        self.io = io;
+        state
+    }
+
+    pub fn read_input(&mut self, input: MemoryValue) {
+        self.io.input.push(input);
     }
 
-    fn get_value(&self, index: MemoryIndex) -> Parameter {
-        Parameter::Value {
-            value: self.get_parameter(index),
+    /// Executes a single instruction, reading from and writing to `io`.
+    pub fn step_with<D: IoDevice + ?Sized>(&mut self, io: &mut D) -> State {
+        self.tick(io)
+    }
+
+    /// Runs against `io` until the program halts or `io` has no more input to offer.
+    /// Returns either `State::Halt` or `State::WaitingForInput`.
+    pub fn run_with<D: IoDevice + ?Sized>(&mut self, io: &mut D) -> State {
+        loop {
+            match self.tick(io) {
+                state @ State::Halt | state @ State::WaitingForInput => return state,
+                _ => continue,
+            }
         }
     }
 
-    pub fn run(&mut self, input: Memory) -> Output {
-        self.input = input.clone();
+    /// Like `step_with`, but reports malformed programs instead of panicking.
+    /// A failed instruction leaves the computer untouched.
+    pub fn try_step_with<D: IoDevice + ?Sized>(
+        &mut self,
+        io: &mut D,
+    ) -> Result<State, IntCodeError> {
+        self.try_tick(io)
+    }
+
+    /// Like `run_with`, but reports malformed programs instead of panicking.
+    pub fn try_run_with<D: IoDevice + ?Sized>(
+        &mut self,
+        io: &mut D,
+    ) -> Result<State, IntCodeError> {
         loop {
-            let state = self.tick();
-            match state {
-                State::Halt => break,
+            match self.try_tick(io)? {
+                state @ State::Halt | state @ State::WaitingForInput => return Ok(state),
                 _ => continue,
             }
         }
+    }
 
-        self.output
-            .clone()
-            .iter()
-            .map(|x| format!("{:?}", x))
-            .collect()
+    fn target_address(&self, target: &Parameter) -> Result<MemoryIndex, IntCodeError> {
+        match target {
+            Parameter::Reference { address } if *address < self.memory.len() => Ok(*address),
+            Parameter::Reference { address } => Err(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address: *address as MemoryValue,
+            }),
+            Parameter::Value { .. } => Err(IntCodeError::WriteToImmediate { pc: self.pc }),
+        }
     }
 
-    pub fn step(&mut self) -> State {
-        self.tick()
+    fn write(&mut self, target: &Parameter, value: MemoryValue) -> Result<(), IntCodeError> {
+        let address = self.target_address(target)?;
+        self.memory[address] = value;
+        Ok(())
     }
 
-    pub fn read_input(&mut self, input: MemoryValue) {
-        self.input.push(input);
+    fn jump(&mut self, target: MemoryValue) -> Result<State, IntCodeError> {
+        if target < 0 {
+            return Err(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address: target,
+            });
+        }
+        self.pc = target as MemoryIndex;
+        Ok(State::Jump)
     }
 
-    fn execute_command(&mut self, operation: &Operation) -> State {
-        match operation {
-            Operation::Input(Parameter::Reference { address: v }) => match self.input.pop() {
-                Some(value) => {
-                    let cell = self.memory.get_mut(*v).unwrap();
-                    *cell = value;
-                    State::Running
+    fn execute_command<D: IoDevice + ?Sized>(
+        &mut self,
+        operation: &Operation,
+        io: &mut D,
+    ) -> Result<State, IntCodeError> {
+        let pc = self.pc;
+        let overflow = IntCodeError::Overflow { pc };
+
+        let state = match operation {
+            Operation::Input(target) => {
+                // Validate the target first, so no input is lost on a faulty write
+                self.target_address(target)?;
+                match io.input() {
+                    Some(value) => {
+                        self.write(target, value)?;
+                        State::Running
+                    }
+                    None => State::WaitingForInput,
                 }
-                None => State::WaitingForInput,
-            },
-            Operation::Add(a, b, Parameter::Reference { address: target }) => {
-                let x = a.eval(&self.memory);
-                let y = b.eval(&self.memory);
-                self.memory[*target] = x + y;
+            }
+            Operation::Add(a, b, target) => {
+                let x = a.eval(&self.memory, pc)?;
+                let y = b.eval(&self.memory, pc)?;
+                self.write(target, x.checked_add(y).ok_or(overflow)?)?;
                 State::Running
             }
-            Operation::Mul(a, b, Parameter::Reference { address: target }) => {
-                let x = a.eval(&self.memory);
-                let y = b.eval(&self.memory);
-                self.memory[*target] = x * y;
+            Operation::Mul(a, b, target) => {
+                let x = a.eval(&self.memory, pc)?;
+                let y = b.eval(&self.memory, pc)?;
+                self.write(target, x.checked_mul(y).ok_or(overflow)?)?;
                 State::Running
             }
             Operation::JumpTrue(a, b) => {
-                let val = a.eval(&self.memory);
-                let pointer = b.eval(&self.memory) as MemoryIndex;
+                let val = a.eval(&self.memory, pc)?;
+                let pointer = b.eval(&self.memory, pc)?;
                 if val != 0 {
-                    self.pc = pointer;
-                    State::Jump
+                    self.jump(pointer)?
                 } else {
                     State::Running
                 }
             }
             Operation::JumpFalse(a, b) => {
-                let val = a.eval(&self.memory);
-                let pointer = b.eval(&self.memory) as MemoryIndex;
+                let val = a.eval(&self.memory, pc)?;
+                let pointer = b.eval(&self.memory, pc)?;
                 if val == 0 {
-                    self.pc = pointer;
-                    State::Jump
+                    self.jump(pointer)?
                 } else {
                     State::Running
                 }
             }
-            Operation::LessThan(a, b, Parameter::Reference { address: target }) => {
-                if a.eval(&self.memory) < b.eval(&self.memory) {
-                    self.memory[*target] = 1;
-                } else {
-                    self.memory[*target] = 0;
-                }
+            Operation::LessThan(a, b, target) => {
+                let result = a.eval(&self.memory, pc)? < b.eval(&self.memory, pc)?;
+                self.write(target, result as MemoryValue)?;
                 State::Running
             }
-            Operation::Equal(a, b, Parameter::Reference { address: target }) => {
-                if a.eval(&self.memory) == b.eval(&self.memory) {
-                    self.memory[*target] = 1;
-                } else {
-                    self.memory[*target] = 0;
-                }
+            Operation::Equal(a, b, target) => {
+                let result = a.eval(&self.memory, pc)? == b.eval(&self.memory, pc)?;
+                self.write(target, result as MemoryValue)?;
                 State::Running
             }
             Operation::Output(address) => {
-                let value = address.eval(&self.memory);
-                self.output.push(value.clone());
+                let value = address.eval(&self.memory, pc)?;
+                io.output(value);
                 State::Output(value)
             }
             Operation::Halt => State::Halt,
             Operation::AdjustRelativeBase(a) => {
-                let value = a.eval(&self.memory);
-                self.relative_base = (self.relative_base as isize + value) as usize;
+                let value = a.eval(&self.memory, pc)?;
+                self.relative_base = self.relative_base.checked_add(value).ok_or(overflow)?;
                 State::Running
             }
-            x => panic!("Unknown Operation: {:?}", x),
-        }
+        };
+
+        Ok(state)
     }
 }
 
@@ -328,20 +484,25 @@ pub enum State {
 
 #[cfg(test)]
 mod test {
-    use crate::{IntCodeComputer, Memory, Mode, Output};
+    use crate::{
+        AsciiDevice, ChannelDevice, FnDevice, IntCodeComputer, Memory, Mode, Output, QueueDevice,
+        State, TeeDevice,
+    };
+    use std::sync::mpsc::channel;
+    use std::thread;
 
     #[test]
     fn test_parse_instruction_code() {
         assert_eq!(
-            IntCodeComputer::decode_opcode(102).1,
+            IntCodeComputer::decode_opcode(102).unwrap().1,
             (Mode::Immediate, Mode::Position, Mode::Position)
         );
         assert_eq!(
-            IntCodeComputer::decode_opcode(01).1,
+            IntCodeComputer::decode_opcode(1).unwrap().1,
             (Mode::Position, Mode::Position, Mode::Position)
         );
         assert_eq!(
-            IntCodeComputer::decode_opcode(11101).1,
+            IntCodeComputer::decode_opcode(11101).unwrap().1,
             (Mode::Immediate, Mode::Immediate, Mode::Immediate)
         );
     }
@@ -413,7 +574,7 @@ mod test {
 
         let mut comp = IntCodeComputer::new(program);
         comp.run(input);
-        assert_eq!(output, comp.output);
+        assert_eq!(output, comp.get_output());
     }
 
     #[test]
@@ -424,7 +585,7 @@ mod test {
 
         let mut comp = IntCodeComputer::new(program);
         comp.run(input);
-        assert_eq!(output, comp.output);
+        assert_eq!(output, comp.get_output());
     }
 
     #[test]
@@ -434,7 +595,58 @@ mod test {
 
         let mut comp = IntCodeComputer::new(program);
         comp.run(input);
-        let last_output = comp.output.first().unwrap().to_string();
+        let last_output = comp.get_output().first().unwrap().to_string();
         assert_eq!(last_output.len(), 16);
     }
+
+    #[test]
+    fn test_queue_and_tee_devices() {
+        // Adds its two inputs and outputs the sum
+        let program = vec![3, 20, 3, 21, 1, 20, 21, 22, 4, 22, 99];
+
+        let mut device = TeeDevice::new(QueueDevice::new(vec![3]));
+        let mut comp = IntCodeComputer::new(program);
+        assert_eq!(comp.run_with(&mut device), State::WaitingForInput);
+
+        device.inner.push(4);
+        assert_eq!(comp.run_with(&mut device), State::Halt);
+        assert_eq!(device.inputs, vec![3, 4]);
+        assert_eq!(device.outputs, vec![7]);
+        assert_eq!(device.inner.output, vec![7]);
+    }
+
+    #[test]
+    fn test_fn_and_ascii_devices() {
+        // Echoes every input until it reads a zero
+        let program = vec![3, 100, 1006, 100, 10, 4, 100, 1105, 1, 0, 104, 1000, 99];
+
+        let mut inputs = vec![0, 2, 1];
+        let mut outputs = vec![];
+        let mut device = FnDevice::new(|| inputs.pop(), |v| outputs.push(v));
+        IntCodeComputer::new(program.clone()).run_with(&mut device);
+        assert_eq!(outputs, vec![1, 2, 1000]);
+
+        let mut device = AsciiDevice::new("hi\n".as_bytes(), vec![]);
+        let state = IntCodeComputer::new(program).run_with(&mut device);
+        assert_eq!(state, State::WaitingForInput);
+        assert_eq!(device.into_writer(), b"hi\n".to_vec());
+    }
+
+    #[test]
+    fn test_channel_device() {
+        let program = vec![3, 100, 1006, 100, 10, 4, 100, 1105, 1, 0, 104, 1000, 99];
+        let (to_cpu, cpu_input) = channel();
+        let (cpu_output, from_cpu) = channel();
+
+        let handle = thread::spawn(move || {
+            let mut device = ChannelDevice::new(cpu_input, cpu_output);
+            IntCodeComputer::new(program).run_with(&mut device)
+        });
+
+        to_cpu.send(42).unwrap();
+        assert_eq!(from_cpu.recv().unwrap(), 42);
+        to_cpu.send(0).unwrap();
+        assert_eq!(from_cpu.recv().unwrap(), 1000);
+        assert_eq!(handle.join().unwrap(), State::Halt);
+    }
 }
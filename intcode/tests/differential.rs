@@ -0,0 +1,746 @@
+//! Differential and crash-only fuzzing of the Intcode VM.
+//!
+//! Every engine executes the same generated program with the same input and has to end up
+//! in exactly the same state. Patched copies of the corpus programs are fuzzed as well, so
+//! that the compiled machines run their own code and not only the interpreter fallback. New
+//! engines only need an `Engine` implementation and an entry in `engines()` to be covered.
+
+use intcode::{IntCodeComputer, IntCodeError, Memory, MemoryValue, QueueDevice, State};
+use intcode_compiled as compiled;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::FileFailurePersistence;
+use std::collections::HashMap;
+
+const STEP_BUDGET: usize = 2_000;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Stop {
+    Halt,
+    WaitingForInput,
+    OutOfSteps,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Outcome {
+    result: Result<Stop, IntCodeError>,
+    output: Memory,
+    memory: Memory,
+}
+
+trait Engine {
+    fn name(&self) -> &'static str;
+
+    /// Engines which panic on faulty programs only run when the reference succeeds.
+    fn handles_errors(&self) -> bool {
+        true
+    }
+
+    fn execute(&self, program: &[MemoryValue], input: &[MemoryValue]) -> Outcome;
+}
+
+/// A deliberately naive interpreter, written independently of the VM.
+struct Reference;
+
+struct ReferenceState {
+    memory: Memory,
+    pc: usize,
+    relative_base: MemoryValue,
+}
+
+impl ReferenceState {
+    fn read(&self, address: usize) -> Result<MemoryValue, IntCodeError> {
+        self.memory
+            .get(address)
+            .copied()
+            .ok_or(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address: address as MemoryValue,
+            })
+    }
+
+    /// Returns the immediate value or the resolved address of the nth parameter.
+    fn parameter(&self, n: usize, mode: MemoryValue) -> Result<(bool, MemoryValue), IntCodeError> {
+        let raw = self.read(self.pc + n)?;
+        let address = match mode {
+            1 => return Ok((true, raw)),
+            0 => raw,
+            _ => self
+                .relative_base
+                .checked_add(raw)
+                .ok_or(IntCodeError::Overflow { pc: self.pc })?,
+        };
+        if address < 0 {
+            return Err(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address,
+            });
+        }
+        Ok((false, address))
+    }
+
+    fn value(&self, parameter: (bool, MemoryValue)) -> Result<MemoryValue, IntCodeError> {
+        match parameter {
+            (true, value) => Ok(value),
+            (false, address) => self.read(address as usize),
+        }
+    }
+
+    fn target(&self, parameter: (bool, MemoryValue)) -> Result<usize, IntCodeError> {
+        match parameter {
+            (true, _) => Err(IntCodeError::WriteToImmediate { pc: self.pc }),
+            (false, address) if (address as usize) < self.memory.len() => Ok(address as usize),
+            (false, address) => Err(IntCodeError::InvalidAddress {
+                pc: self.pc,
+                address,
+            }),
+        }
+    }
+
+    fn step(
+        &mut self,
+        input: &mut dyn Iterator<Item = MemoryValue>,
+        output: &mut Memory,
+    ) -> Result<Option<Stop>, IntCodeError> {
+        let pc = self.pc;
+        let instruction = self.read(pc)?;
+        let invalid = IntCodeError::InvalidInstruction { pc, instruction };
+        let modes = [
+            instruction / 100 % 10,
+            instruction / 1_000 % 10,
+            instruction / 10_000 % 10,
+        ];
+        if instruction < 0 || modes.iter().any(|m| *m > 2) {
+            return Err(invalid);
+        }
+
+        let arity = match instruction % 100 {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            3 | 4 | 9 => 1,
+            99 => 0,
+            _ => return Err(invalid),
+        };
+        let mut params = vec![];
+        for (n, mode) in modes.iter().enumerate().take(arity) {
+            params.push(self.parameter(n + 1, *mode)?);
+        }
+
+        let overflow = IntCodeError::Overflow { pc };
+        match instruction % 100 {
+            99 => return Ok(Some(Stop::Halt)),
+            opcode @ 1 | opcode @ 2 | opcode @ 7 | opcode @ 8 => {
+                let (x, y) = (self.value(params[0])?, self.value(params[1])?);
+                let result = match opcode {
+                    1 => x.checked_add(y).ok_or(overflow)?,
+                    2 => x.checked_mul(y).ok_or(overflow)?,
+                    7 => (x < y) as MemoryValue,
+                    _ => (x == y) as MemoryValue,
+                };
+                let target = self.target(params[2])?;
+                self.memory[target] = result;
+            }
+            3 => {
+                let target = self.target(params[0])?;
+                match input.next() {
+                    Some(value) => self.memory[target] = value,
+                    None => return Ok(Some(Stop::WaitingForInput)),
+                }
+            }
+            4 => output.push(self.value(params[0])?),
+            opcode @ 5 | opcode @ 6 => {
+                let (condition, target) = (self.value(params[0])?, self.value(params[1])?);
+                if (condition != 0) == (opcode == 5) {
+                    if target < 0 {
+                        return Err(IntCodeError::InvalidAddress {
+                            pc,
+                            address: target,
+                        });
+                    }
+                    self.pc = target as usize;
+                    return Ok(None);
+                }
+            }
+            _ => {
+                let value = self.value(params[0])?;
+                self.relative_base = self.relative_base.checked_add(value).ok_or(overflow)?;
+            }
+        }
+
+        self.pc += arity + 1;
+        Ok(None)
+    }
+}
+
+impl Engine for Reference {
+    fn name(&self) -> &'static str {
+        "reference"
+    }
+
+    fn execute(&self, program: &[MemoryValue], input: &[MemoryValue]) -> Outcome {
+        let mut memory = program.to_vec();
+        memory.resize(program.len() * 11, 0);
+        let mut state = ReferenceState {
+            memory,
+            pc: 0,
+            relative_base: 0,
+        };
+        let mut input = input.iter().copied();
+        let mut output = vec![];
+
+        let mut result = Ok(Stop::OutOfSteps);
+        for _ in 0..STEP_BUDGET {
+            match state.step(&mut input, &mut output) {
+                Ok(None) => continue,
+                Ok(Some(stop)) => result = Ok(stop),
+                Err(e) => result = Err(e),
+            }
+            break;
+        }
+
+        Outcome {
+            result,
+            output,
+            memory: state.memory,
+        }
+    }
+}
+
+/// The VM driven through an `IoDevice` via `try_step_with`.
+struct Device;
+
+impl Engine for Device {
+    fn name(&self) -> &'static str {
+        "device"
+    }
+
+    fn execute(&self, program: &[MemoryValue], input: &[MemoryValue]) -> Outcome {
+        let mut cpu = IntCodeComputer::new(program.to_vec());
+        let mut device = QueueDevice::new(input.iter().copied());
+
+        let mut result = Ok(Stop::OutOfSteps);
+        for _ in 0..STEP_BUDGET {
+            match cpu.try_step_with(&mut device) {
+                Ok(State::Halt) => result = Ok(Stop::Halt),
+                Ok(State::WaitingForInput) => result = Ok(Stop::WaitingForInput),
+                Ok(_) => continue,
+                Err(e) => result = Err(e),
+            }
+            break;
+        }
+
+        Outcome {
+            result,
+            output: device.output,
+            memory: cpu.get_memory(),
+        }
+    }
+}
+
+/// The VM driven through its own buffers via `read_input` and `step`.
+struct Buffered;
+
+impl Engine for Buffered {
+    fn name(&self) -> &'static str {
+        "buffered"
+    }
+
+    fn handles_errors(&self) -> bool {
+        false
+    }
+
+    fn execute(&self, program: &[MemoryValue], input: &[MemoryValue]) -> Outcome {
+        let mut cpu = IntCodeComputer::new(program.to_vec());
+        // Buffered input is consumed from the back
+        input.iter().rev().for_each(|value| cpu.read_input(*value));
+
+        let mut result = Ok(Stop::OutOfSteps);
+        for _ in 0..STEP_BUDGET {
+            match cpu.step() {
+                State::Halt => result = Ok(Stop::Halt),
+                State::WaitingForInput => result = Ok(Stop::WaitingForInput),
+                _ => continue,
+            }
+            break;
+        }
+
+        Outcome {
+            result,
+            output: cpu.get_output(),
+            memory: cpu.get_memory(),
+        }
+    }
+}
+
+/// A second interpreter written independently of the VM, which decodes every instruction
+/// up front and only stores the cells of its memory which are not zero.
+struct Sparse;
+
+struct SparseMemory {
+    cells: HashMap<usize, MemoryValue>,
+    len: usize,
+}
+
+impl SparseMemory {
+    fn new(program: &[MemoryValue]) -> Self {
+        let cells = program
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value != 0)
+            .map(|(address, value)| (address, *value))
+            .collect();
+        SparseMemory {
+            cells,
+            len: program.len() * 11,
+        }
+    }
+
+    fn get(&self, address: MemoryValue) -> Option<MemoryValue> {
+        if address < 0 || address as usize >= self.len {
+            return None;
+        }
+        Some(self.cells.get(&(address as usize)).copied().unwrap_or(0))
+    }
+
+    fn set(&mut self, address: usize, value: MemoryValue) {
+        if value == 0 {
+            self.cells.remove(&address);
+        } else {
+            self.cells.insert(address, value);
+        }
+    }
+
+    fn to_vec(&self) -> Memory {
+        (0..self.len)
+            .map(|address| self.cells.get(&address).copied().unwrap_or(0))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operand {
+    Immediate(MemoryValue),
+    Address(MemoryValue),
+}
+
+enum Op {
+    Arithmetic(MemoryValue, [Operand; 3]),
+    Input(Operand),
+    Output(Operand),
+    JumpIf(bool, [Operand; 2]),
+    AdjustBase(Operand),
+    Halt,
+}
+
+struct SparseMachine<'a> {
+    memory: SparseMemory,
+    pc: MemoryValue,
+    relative_base: MemoryValue,
+    input: &'a [MemoryValue],
+    output: Memory,
+}
+
+impl SparseMachine<'_> {
+    fn load(&self, address: MemoryValue) -> Result<MemoryValue, IntCodeError> {
+        self.memory
+            .get(address)
+            .ok_or(IntCodeError::InvalidAddress {
+                pc: self.pc as usize,
+                address,
+            })
+    }
+
+    fn decode(&self) -> Result<(Op, MemoryValue), IntCodeError> {
+        let pc = self.pc as usize;
+        let instruction = self.load(self.pc)?;
+        let invalid = IntCodeError::InvalidInstruction { pc, instruction };
+        if instruction < 0
+            || [100, 1_000, 10_000]
+                .iter()
+                .any(|d| instruction / d % 10 > 2)
+        {
+            return Err(invalid);
+        }
+
+        let arity = match instruction % 100 {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            3 | 4 | 9 => 1,
+            99 => 0,
+            _ => return Err(invalid),
+        };
+        let mut operands = [Operand::Immediate(0); 3];
+        let mut divisor = 100;
+        for (n, operand) in operands.iter_mut().enumerate().take(arity) {
+            let raw = self.load(self.pc + n as MemoryValue + 1)?;
+            *operand = match instruction / divisor % 10 {
+                1 => Operand::Immediate(raw),
+                0 => Operand::Address(raw),
+                _ => Operand::Address(
+                    self.relative_base
+                        .checked_add(raw)
+                        .ok_or(IntCodeError::Overflow { pc })?,
+                ),
+            };
+            if let Operand::Address(address) = operand {
+                if *address < 0 {
+                    return Err(IntCodeError::InvalidAddress {
+                        pc,
+                        address: *address,
+                    });
+                }
+            }
+            divisor *= 10;
+        }
+
+        let [a, b, c] = operands;
+        let op = match instruction % 100 {
+            opcode @ 1 | opcode @ 2 | opcode @ 7 | opcode @ 8 => Op::Arithmetic(opcode, [a, b, c]),
+            3 => Op::Input(a),
+            4 => Op::Output(a),
+            opcode @ 5 | opcode @ 6 => Op::JumpIf(opcode == 5, [a, b]),
+            9 => Op::AdjustBase(a),
+            _ => Op::Halt,
+        };
+        Ok((op, arity as MemoryValue + 1))
+    }
+
+    fn value(&self, operand: Operand) -> Result<MemoryValue, IntCodeError> {
+        match operand {
+            Operand::Immediate(value) => Ok(value),
+            Operand::Address(address) => self.load(address),
+        }
+    }
+
+    fn target(&self, operand: Operand) -> Result<usize, IntCodeError> {
+        let pc = self.pc as usize;
+        match operand {
+            Operand::Immediate(_) => Err(IntCodeError::WriteToImmediate { pc }),
+            Operand::Address(address) if (address as usize) < self.memory.len => {
+                Ok(address as usize)
+            }
+            Operand::Address(address) => Err(IntCodeError::InvalidAddress { pc, address }),
+        }
+    }
+
+    fn run(&mut self) -> Result<Stop, IntCodeError> {
+        for _ in 0..STEP_BUDGET {
+            let pc = self.pc as usize;
+            let overflow = IntCodeError::Overflow { pc };
+            let (op, size) = self.decode()?;
+            match op {
+                Op::Halt => return Ok(Stop::Halt),
+                Op::Arithmetic(opcode, [a, b, c]) => {
+                    let (a, b) = (self.value(a)?, self.value(b)?);
+                    let result = match opcode {
+                        1 => a.checked_add(b).ok_or(overflow)?,
+                        2 => a.checked_mul(b).ok_or(overflow)?,
+                        7 => (a < b) as MemoryValue,
+                        _ => (a == b) as MemoryValue,
+                    };
+                    let target = self.target(c)?;
+                    self.memory.set(target, result);
+                }
+                Op::Input(a) => {
+                    let target = self.target(a)?;
+                    match self.input.split_first() {
+                        Some((value, rest)) => {
+                            self.memory.set(target, *value);
+                            self.input = rest;
+                        }
+                        None => return Ok(Stop::WaitingForInput),
+                    }
+                }
+                Op::Output(a) => {
+                    let value = self.value(a)?;
+                    self.output.push(value);
+                }
+                Op::JumpIf(if_true, [a, b]) => {
+                    let (condition, target) = (self.value(a)?, self.value(b)?);
+                    if (condition != 0) == if_true {
+                        if target < 0 {
+                            return Err(IntCodeError::InvalidAddress {
+                                pc,
+                                address: target,
+                            });
+                        }
+                        self.pc = target;
+                        continue;
+                    }
+                }
+                Op::AdjustBase(a) => {
+                    let value = self.value(a)?;
+                    self.relative_base = self.relative_base.checked_add(value).ok_or(overflow)?;
+                }
+            }
+            self.pc += size;
+        }
+        Ok(Stop::OutOfSteps)
+    }
+}
+
+impl Engine for Sparse {
+    fn name(&self) -> &'static str {
+        "sparse"
+    }
+
+    fn execute(&self, program: &[MemoryValue], input: &[MemoryValue]) -> Outcome {
+        let mut machine = SparseMachine {
+            memory: SparseMemory::new(program),
+            pc: 0,
+            relative_base: 0,
+            input,
+            output: vec![],
+        };
+        let result = machine.run();
+
+        Outcome {
+            result,
+            output: machine.output,
+            memory: machine.memory.to_vec(),
+        }
+    }
+}
+
+/// The corpus programs compiled ahead of time, picked by the length of the program. Other
+/// programs run on the machine of day 2, which only exercises its guards and the fallback.
+struct Compiled;
+
+macro_rules! compiled_days {
+    ($macro:ident) => {
+        $macro!(day02, day05, day07, day09, day11, day13, day15, day17, day19, day21)
+    };
+}
+
+impl Engine for Compiled {
+    fn name(&self) -> &'static str {
+        "compiled"
+    }
+
+    fn execute(&self, program: &[MemoryValue], input: &[MemoryValue]) -> Outcome {
+        let mut device = QueueDevice::new(input.iter().copied());
+        macro_rules! dispatch {
+            ($($module:ident),*) => {
+                match program.len() {
+                    $(len if len == compiled::$module::PROGRAM.len() => {
+                        let mut machine = compiled::$module::Machine::with_program(program.to_vec());
+                        let state = machine.run_steps(&mut device, STEP_BUDGET);
+                        (state, machine.memory().to_vec())
+                    })*
+                    _ => {
+                        let mut machine = compiled::day02::Machine::with_program(program.to_vec());
+                        let state = machine.run_steps(&mut device, STEP_BUDGET);
+                        (state, machine.memory().to_vec())
+                    }
+                }
+            };
+        }
+        let (state, memory) = compiled_days!(dispatch);
+
+        let result = state.map(|state| match state {
+            State::Halt => Stop::Halt,
+            State::WaitingForInput => Stop::WaitingForInput,
+            _ => Stop::OutOfSteps,
+        });
+        Outcome {
+            result,
+            output: device.output,
+            memory,
+        }
+    }
+}
+
+fn engines() -> Vec<Box<dyn Engine>> {
+    vec![
+        Box::new(Device),
+        Box::new(Buffered),
+        Box::new(Sparse),
+        Box::new(Compiled),
+    ]
+}
+
+fn assert_engines_agree(program: &[MemoryValue], input: &[MemoryValue]) {
+    let expected = Reference.execute(program, input);
+    for engine in engines() {
+        if expected.result.is_err() && !engine.handles_errors() {
+            continue;
+        }
+        let actual = engine.execute(program, input);
+        assert_eq!(
+            actual,
+            expected,
+            "{} disagrees with {} on {:?}",
+            engine.name(),
+            Reference.name(),
+            program
+        );
+    }
+}
+
+/// A single parameter as (mode, raw value), write targets are never immediate.
+fn parameter(write: bool) -> BoxedStrategy<(MemoryValue, MemoryValue)> {
+    let position = (0..48isize).prop_map(|v| (0, v));
+    let relative = (0..48isize).prop_map(|v| (2, v));
+    if write {
+        prop_oneof![position, relative].boxed()
+    } else {
+        let immediate = (-8..64isize).prop_map(|v| (1, v));
+        prop_oneof![position, immediate, relative].boxed()
+    }
+}
+
+fn instruction() -> impl Strategy<Value = Memory> {
+    let binary = (
+        prop_oneof![Just(1), Just(2), Just(7), Just(8)],
+        parameter(false),
+        parameter(false),
+        parameter(true),
+    )
+        .prop_map(|(op, a, b, c)| vec![op + 100 * a.0 + 1_000 * b.0 + 10_000 * c.0, a.1, b.1, c.1]);
+    let jump = (5..7isize, parameter(false), parameter(false))
+        .prop_map(|(op, a, b)| vec![op + 100 * a.0 + 1_000 * b.0, a.1, b.1]);
+    let input = parameter(true).prop_map(|a| vec![3 + 100 * a.0, a.1]);
+    let unary = (prop_oneof![Just(4), Just(9)], parameter(false))
+        .prop_map(|(op, a)| vec![op + 100 * a.0, a.1]);
+
+    prop_oneof![4 => binary, 2 => jump, 1 => input, 2 => unary]
+}
+
+fn program() -> impl Strategy<Value = Memory> {
+    (vec(instruction(), 1..24), vec(-64..64isize, 4..16)).prop_map(|(instructions, data)| {
+        let mut program = instructions.concat();
+        program.push(99);
+        program.extend(data);
+        program
+    })
+}
+
+fn corpus_program() -> impl Strategy<Value = Memory> {
+    macro_rules! programs {
+        ($($module:ident),*) => {
+            vec![$(compiled::$module::PROGRAM.to_vec()),*]
+        };
+    }
+    proptest::sample::select(compiled_days!(programs))
+}
+
+/// A corpus program with a few cells overwritten, so that most of it still runs compiled.
+fn patched_corpus_program() -> impl Strategy<Value = Memory> {
+    (
+        corpus_program(),
+        vec((any::<proptest::sample::Index>(), -64..64isize), 0..4),
+    )
+        .prop_map(|(mut program, patches)| {
+            for (index, value) in patches {
+                let address = index.index(program.len());
+                program[address] = value;
+            }
+            program
+        })
+}
+
+/// Arbitrary memory, biased towards values which decode to something meaningful.
+fn memory() -> impl Strategy<Value = Memory> {
+    vec(
+        prop_oneof![-64..64isize, 0..30_000isize, any::<isize>()],
+        0..64,
+    )
+}
+
+proptest! {
+    // Failures are kept next to this crate, so that they are replayed before any new cases
+    #![proptest_config(ProptestConfig {
+        failure_persistence: Some(Box::new(FileFailurePersistence::Direct(
+            "proptest-regressions/differential.txt",
+        ))),
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn test_engines_agree_on_generated_programs(program in program(), input in vec(-64..64isize, 0..8)) {
+        assert_engines_agree(&program, &input);
+    }
+
+    #[test]
+    fn test_engines_agree_on_patched_corpus(program in patched_corpus_program(), input in vec(-64..64isize, 0..16)) {
+        assert_engines_agree(&program, &input);
+    }
+
+    #[test]
+    fn test_engines_agree_on_arbitrary_memory(program in memory(), input in vec(any::<isize>(), 0..8)) {
+        assert_engines_agree(&program, &input);
+    }
+
+    #[test]
+    fn test_arbitrary_memory_never_panics(program in memory(), input in vec(any::<isize>(), 0..8)) {
+        Device.execute(&program, &input);
+    }
+}
+
+#[test]
+fn test_error_paths() {
+    let specs: Vec<(Memory, IntCodeError)> = vec![
+        (vec![], IntCodeError::InvalidAddress { pc: 0, address: 0 }),
+        (
+            vec![42],
+            IntCodeError::InvalidInstruction {
+                pc: 0,
+                instruction: 42,
+            },
+        ),
+        (
+            vec![301, 0, 0, 0],
+            IntCodeError::InvalidInstruction {
+                pc: 0,
+                instruction: 301,
+            },
+        ),
+        (
+            vec![-1],
+            IntCodeError::InvalidInstruction {
+                pc: 0,
+                instruction: -1,
+            },
+        ),
+        (
+            vec![1, 0, 0, -1],
+            IntCodeError::InvalidAddress { pc: 0, address: -1 },
+        ),
+        (
+            vec![4, 1000],
+            IntCodeError::InvalidAddress {
+                pc: 0,
+                address: 1000,
+            },
+        ),
+        (
+            vec![11101, 1, 1, 0],
+            IntCodeError::WriteToImmediate { pc: 0 },
+        ),
+        (
+            vec![1105, 1, -3],
+            IntCodeError::InvalidAddress { pc: 0, address: -3 },
+        ),
+        (
+            vec![204, -1],
+            IntCodeError::InvalidAddress { pc: 0, address: -1 },
+        ),
+        (
+            vec![1102, isize::MAX, 2, 0],
+            IntCodeError::Overflow { pc: 0 },
+        ),
+        (
+            vec![109, isize::MAX, 109, 1],
+            IntCodeError::Overflow { pc: 2 },
+        ),
+    ];
+
+    for (program, error) in specs {
+        let mut cpu = IntCodeComputer::new(program.clone());
+        let memory = cpu.get_memory();
+        let result = cpu.try_run_with(&mut QueueDevice::default());
+        assert_eq!(result, Err(error), "{:?}", program);
+        assert_eq!(cpu.get_memory(), memory);
+        assert_engines_agree(&program, &[]);
+    }
+}
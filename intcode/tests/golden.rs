@@ -0,0 +1,170 @@
+//! Replays the recorded puzzle transcripts in `corpus/` against the VM.
+//!
+//! Each corpus file starts with a `program:` line followed by any number of cases:
+//!
+//! ```text
+//! case: <name>
+//! patch: <address>=<value>,...   (optional, applied to the program before running)
+//! input: <values>                (optional, fed in order)
+//! output: <values>               (optional, the exact expected output)
+//! memory: <address>=<value>,...  (optional, expected cells after running)
+//! state: waiting                 (optional, defaults to halt)
+//! ```
+//!
+//! Every case runs on the interpreter and on the program compiled by `intcode_compiled`.
+
+use intcode::{
+    IntCodeComputer, IntCodeError, Memory, MemoryIndex, MemoryValue, QueueDevice, State,
+};
+use intcode_compiled as compiled;
+use std::fs;
+use std::path::Path;
+
+const DAYS: [&str; 10] = ["02", "05", "07", "09", "11", "13", "15", "17", "19", "21"];
+
+#[derive(Debug, Default)]
+struct Case {
+    name: String,
+    patch: Vec<(MemoryIndex, MemoryValue)>,
+    input: Memory,
+    output: Memory,
+    memory: Vec<(MemoryIndex, MemoryValue)>,
+    waiting: bool,
+}
+
+fn parse_values(values: &str) -> Memory {
+    values
+        .split(',')
+        .map(|v| v.trim().parse().unwrap())
+        .collect()
+}
+
+fn parse_cells(cells: &str) -> Vec<(MemoryIndex, MemoryValue)> {
+    cells
+        .split(',')
+        .map(|cell| {
+            let mut parts = cell.split('=');
+            let address = parts.next().unwrap().trim().parse().unwrap();
+            let value = parts.next().unwrap().trim().parse().unwrap();
+            (address, value)
+        })
+        .collect()
+}
+
+fn parse_corpus(text: &str) -> (Memory, Vec<Case>) {
+    let mut program = vec![];
+    let mut cases: Vec<Case> = vec![];
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line.split_at(line.find(':').unwrap());
+        let value = value[1..].trim();
+
+        if key == "program" {
+            program = parse_values(value);
+            continue;
+        }
+        if key == "case" {
+            cases.push(Case {
+                name: value.to_owned(),
+                ..Case::default()
+            });
+            continue;
+        }
+
+        let case = cases.last_mut().expect("Case attributes before any case");
+        match key {
+            "patch" => case.patch = parse_cells(value),
+            "input" => case.input = parse_values(value),
+            "output" => case.output = parse_values(value),
+            "memory" => case.memory = parse_cells(value),
+            "state" => case.waiting = value == "waiting",
+            _ => panic!("Unknown corpus key: {}", key),
+        }
+    }
+
+    (program, cases)
+}
+
+type Run = (Result<State, IntCodeError>, Memory);
+
+fn interpret(_day: &str, program: Memory, device: &mut QueueDevice) -> Run {
+    let mut cpu = IntCodeComputer::new(program);
+    let state = cpu.try_run_with(device);
+    (state, cpu.get_memory())
+}
+
+fn run_compiled(day: &str, program: Memory, device: &mut QueueDevice) -> Run {
+    macro_rules! dispatch {
+        ($($name:literal => $module:ident),*) => {
+            match day {
+                $($name => {
+                    let mut machine = compiled::$module::Machine::with_program(program);
+                    let state = machine.run(device);
+                    (state, machine.memory().to_vec())
+                })*
+                _ => panic!("day {} was not compiled", day),
+            }
+        };
+    }
+    dispatch!(
+        "02" => day02, "05" => day05, "07" => day07, "09" => day09, "11" => day11,
+        "13" => day13, "15" => day15, "17" => day17, "19" => day19, "21" => day21
+    )
+}
+
+fn replay(
+    day: &str,
+    program: &[MemoryValue],
+    case: &Case,
+    engine: fn(&str, Memory, &mut QueueDevice) -> Run,
+) {
+    let mut program = program.to_vec();
+    for (address, value) in &case.patch {
+        program[*address] = *value;
+    }
+
+    let mut device = QueueDevice::new(case.input.clone());
+    let (state, memory) = engine(day, program, &mut device);
+
+    let context = format!("day {}, case '{}'", day, case.name);
+    let expected_state = if case.waiting {
+        State::WaitingForInput
+    } else {
+        State::Halt
+    };
+    assert_eq!(state, Ok(expected_state), "{}", context);
+    assert!(device.input.is_empty(), "{}: unread input", context);
+    assert_eq!(device.output, case.output, "{}", context);
+
+    for (address, value) in &case.memory {
+        assert_eq!(memory[*address], *value, "{}: memory[{}]", context, address);
+    }
+}
+
+fn replay_corpus(engine: fn(&str, Memory, &mut QueueDevice) -> Run) {
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus");
+
+    for day in DAYS.iter() {
+        let text = fs::read_to_string(corpus.join(format!("day{}.txt", day))).unwrap();
+        let (program, cases) = parse_corpus(&text);
+        assert!(!program.is_empty(), "day {} has no program", day);
+        assert!(!cases.is_empty(), "day {} has no cases", day);
+
+        for case in &cases {
+            replay(day, &program, case, engine);
+        }
+    }
+}
+
+#[test]
+fn test_golden_corpus() {
+    replay_corpus(interpret);
+}
+
+#[test]
+fn test_golden_corpus_compiled() {
+    replay_corpus(run_compiled);
+}
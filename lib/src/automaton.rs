@@ -0,0 +1,258 @@
+//! Cellular automata like Conway's Game of Life on a `Grid`.
+//!
+//! The neighbours of every cell are resolved once up front, so stepping only reads the
+//! current generation and writes into a second buffer, which is then swapped in.
+
+use crate::{Grid, Point, Topology};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::mem;
+
+/// Computes the next state of a cell from its current state and its live neighbours.
+pub trait Rule {
+    type Cell;
+
+    /// Whether a neighbour counts towards the neighbour count.
+    fn is_live(&self, cell: &Self::Cell) -> bool;
+
+    fn next(&self, cell: &Self::Cell, live_neighbours: usize) -> Self::Cell;
+}
+
+/// Which cells are neighbours of which.
+pub enum Neighbourhood<'a> {
+    /// The cells at each offset, like `NEIGHBOURS4` or `NEIGHBOURS8`.
+    Stencil(&'a [Point], Topology),
+    /// Any positions, those outside of the grid are skipped.
+    Function(Box<dyn Fn(Point) -> Vec<Point> + 'a>),
+}
+
+/// A repetition found by `Automaton::find_cycle`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// The generation in which the repeated state first appeared.
+    pub start: usize,
+    /// The number of generations until it appeared again.
+    pub length: usize,
+}
+
+pub struct Automaton<R: Rule>
+where
+    R::Cell: Debug,
+{
+    rule: R,
+    current: Grid<R::Cell>,
+    next: Grid<R::Cell>,
+    neighbours: Vec<Vec<usize>>,
+    generation: usize,
+}
+
+impl<R: Rule> Automaton<R>
+where
+    R::Cell: Debug + Clone,
+{
+    pub fn new(grid: Grid<R::Cell>, rule: R, neighbourhood: Neighbourhood) -> Self {
+        let neighbours = grid
+            .iter()
+            .map(|((x, y), _)| {
+                let positions = match &neighbourhood {
+                    Neighbourhood::Stencil(stencil, topology) => grid
+                        .neighbours_with(x, y, stencil, *topology)
+                        .map(|(position, _)| position)
+                        .collect(),
+                    Neighbourhood::Function(neighbours) => neighbours((x, y)),
+                };
+                positions
+                    .into_iter()
+                    .filter_map(|(x, y)| grid.coords_to_index(x, y))
+                    .collect()
+            })
+            .collect();
+
+        Automaton {
+            rule,
+            next: grid.clone(),
+            current: grid,
+            neighbours,
+            generation: 0,
+        }
+    }
+}
+
+impl<R: Rule> Automaton<R>
+where
+    R::Cell: Debug,
+{
+    pub fn grid(&self) -> &Grid<R::Cell> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<R::Cell> {
+        self.current
+    }
+
+    /// The number of steps taken so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self) {
+        let (rule, cells) = (&self.rule, &self.current.grid);
+        for (index, next) in self.next.grid.iter_mut().enumerate() {
+            let live_neighbours = self.neighbours[index]
+                .iter()
+                .filter(|&&neighbour| rule.is_live(&cells[neighbour]))
+                .count();
+            *next = rule.next(&cells[index], live_neighbours);
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+}
+
+impl<R: Rule> Automaton<R>
+where
+    R::Cell: Debug + Clone + Hash + Eq,
+{
+    /// Steps until a state repeats, stopping on the repeated state.
+    pub fn find_cycle(&mut self) -> Cycle {
+        self.find_cycle_by(Grid::clone)
+    }
+}
+
+impl<R: Rule> Automaton<R>
+where
+    R::Cell: Debug,
+{
+    /// Like `find_cycle`, but only remembers the `key` of each state, like a packed
+    /// `BitGrid`, which is much cheaper than a copy of the whole grid.
+    pub fn find_cycle_by<K, F>(&mut self, key: F) -> Cycle
+    where
+        K: Hash + Eq,
+        F: Fn(&Grid<R::Cell>) -> K,
+    {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(start) = seen.insert(key(&self.current), self.generation) {
+                return Cycle {
+                    start,
+                    length: self.generation - start,
+                };
+            }
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::automaton::{Automaton, Cycle, Neighbourhood, Rule};
+    use crate::{Grid, Topology, NEIGHBOURS8};
+
+    struct Life;
+
+    impl Rule for Life {
+        type Cell = char;
+
+        fn is_live(&self, cell: &char) -> bool {
+            *cell == '#'
+        }
+
+        fn next(&self, cell: &char, live_neighbours: usize) -> char {
+            match (cell, live_neighbours) {
+                ('#', 2) | (_, 3) => '#',
+                _ => '.',
+            }
+        }
+    }
+
+    #[test]
+    fn test_blinker() {
+        let grid: Grid<char> = ".....\n.....\n.###.\n.....\n.....".parse().unwrap();
+        let neighbourhood = Neighbourhood::Stencil(&NEIGHBOURS8, Topology::Bounded);
+        let mut life = Automaton::new(grid.clone(), Life, neighbourhood);
+
+        life.step();
+        assert_eq!(life.grid().to_string(), ".....\n..#..\n..#..\n..#..\n.....");
+        life.step();
+        assert_eq!(life.grid().to_string(), grid.to_string());
+
+        assert_eq!(
+            life.find_cycle(),
+            Cycle {
+                start: 2,
+                length: 2
+            }
+        );
+        assert_eq!(life.generation(), 4);
+    }
+
+    #[test]
+    fn test_glider_on_torus() {
+        let grid: Grid<char> = ".#....\n..#...\n###...\n......\n......\n......"
+            .parse()
+            .unwrap();
+        let neighbourhood = Neighbourhood::Stencil(&NEIGHBOURS8, Topology::Toroidal);
+        let mut life = Automaton::new(grid.clone(), Life, neighbourhood);
+
+        // A glider moves one cell diagonally every four generations
+        assert_eq!(
+            life.find_cycle(),
+            Cycle {
+                start: 0,
+                length: 24
+            }
+        );
+        assert_eq!(life.grid(), &grid);
+
+        let packed = life.find_cycle_by(|grid| grid.to_bits(|c| *c == '#'));
+        assert_eq!(
+            packed,
+            Cycle {
+                start: 24,
+                length: 24
+            }
+        );
+    }
+
+    #[test]
+    fn test_neighbour_function() {
+        // Every cell only sees the cell to its left, so a live cell travels right
+        let grid: Grid<char> = "#...".parse().unwrap();
+        let neighbourhood = Neighbourhood::Function(Box::new(|(x, y)| vec![(x - 1, y)]));
+
+        struct Shift;
+        impl Rule for Shift {
+            type Cell = char;
+
+            fn is_live(&self, cell: &char) -> bool {
+                *cell == '#'
+            }
+
+            fn next(&self, _: &char, live_neighbours: usize) -> char {
+                if live_neighbours > 0 {
+                    '#'
+                } else {
+                    '.'
+                }
+            }
+        }
+
+        let mut automaton = Automaton::new(grid, Shift, neighbourhood);
+        automaton.run(2);
+        assert_eq!(automaton.grid().to_string(), "..#.");
+        assert_eq!(
+            automaton.find_cycle(),
+            Cycle {
+                start: 4,
+                length: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Grid, Point};
+use std::fmt::{Debug, Display, Error, Formatter};
+
+const WORD: usize = u64::BITS as usize;
+
+/// A grid of two-state cells packed into one bit each, cheap to hash, compare and store.
+/// Bits are numbered in row-major order from `(min_x, min_y)`, like the cells of a `Grid`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    grid_size: (usize, usize),
+    origin: Point,
+}
+
+impl BitGrid {
+    /// Creates a `width` x `height` grid with all bits cleared, starting at `origin`.
+    pub fn new(width: usize, height: usize, origin: Point) -> Self {
+        BitGrid {
+            words: vec![0; (width * height).div_ceil(WORD)],
+            grid_size: (width, height),
+            origin,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid_size.0
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid_size.1
+    }
+
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        let (dx, dy) = (x - self.origin.0, y - self.origin.1);
+        if dx < 0 || dy < 0 || dx as usize >= self.width() || dy as usize >= self.height() {
+            return None;
+        }
+        Some(dy as usize * self.width() + dx as usize)
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.words[index / WORD] & (1 << (index % WORD)) != 0
+    }
+
+    /// Whether the bit at `(x, y)` is set, `None` outside of the grid.
+    pub fn get(&self, x: isize, y: isize) -> Option<bool> {
+        Some(self.bit(self.index(x, y)?))
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn set(&mut self, x: isize, y: isize, value: bool) -> Result<(), ()> {
+        let index = self.index(x, y).ok_or(())?;
+        let mask = 1 << (index % WORD);
+        if value {
+            self.words[index / WORD] |= mask;
+        } else {
+            self.words[index / WORD] &= !mask;
+        }
+        Ok(())
+    }
+
+    /// The number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The packed bits, 64 cells per word starting with the least significant bit.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// The positions of all set bits in row-major order.
+    pub fn ones(&self) -> impl Iterator<Item = Point> + '_ {
+        let (width, origin) = (self.width().max(1), self.origin);
+        (0..self.width() * self.height())
+            .filter(move |&index| self.bit(index))
+            .map(move |index| crate::iter::index_to_point(index, width, origin))
+    }
+
+    /// Unpacks into a `Grid`, with `on` for set and `off` for cleared bits.
+    pub fn to_grid<T: Debug + Clone>(&self, on: T, off: T) -> Grid<T> {
+        let cells = (0..self.width() * self.height())
+            .map(|index| match self.bit(index) {
+                true => on.clone(),
+                false => off.clone(),
+            })
+            .collect();
+        Grid {
+            grid: cells,
+            grid_size: self.grid_size,
+            origin: self.origin,
+        }
+    }
+}
+
+/// Renders set bits as `#` and cleared ones as `.`.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.to_grid('#', '.'))
+    }
+}
+
+impl<T: Debug> Grid<T> {
+    /// Packs the grid into one bit per cell, set wherever `predicate` matches.
+    pub fn to_bits<P: Fn(&T) -> bool>(&self, predicate: P) -> BitGrid {
+        let mut bits = BitGrid::new(self.width(), self.height(), self.origin);
+        for (index, cell) in self.grid.iter().enumerate() {
+            if predicate(cell) {
+                bits.words[index / WORD] |= 1 << (index % WORD);
+            }
+        }
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BitGrid, Grid};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_packing_round_trip() {
+        let grid: Grid<char> = "#..#\n.##.\n#...".parse().unwrap();
+        let bits = grid.to_bits(|c| *c == '#');
+        assert_eq!(bits.count_ones(), 5);
+        assert_eq!(bits.as_words(), &[0b0001_0110_1001]);
+        assert_eq!(bits.get(1, 1), Some(true));
+        assert_eq!(bits.get(1, 0), Some(false));
+        assert_eq!(bits.get(4, 0), None);
+        assert_eq!(bits.to_string(), grid.to_string());
+        assert_eq!(
+            bits.ones().collect::<Vec<_>>(),
+            vec![(0, 0), (3, 0), (1, 1), (2, 1), (0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_large_offset_grid() {
+        let mut bits = BitGrid::new(10, 10, (-5, -5));
+        assert_eq!(bits.as_words().len(), 2);
+        bits.set(4, 4, true).unwrap();
+        bits.set(-5, -5, true).unwrap();
+        assert!(bits.set(5, 5, true).is_err());
+        assert_eq!(bits.ones().collect::<Vec<_>>(), vec![(-5, -5), (4, 4)]);
+
+        bits.set(-5, -5, false).unwrap();
+        assert_eq!(bits.count_ones(), 1);
+        assert_eq!(bits.to_grid(1, 0).get(4, 4), Some(&1));
+    }
+
+    #[test]
+    fn test_hashing_grids() {
+        let a: Grid<char> = "#.\n.#".parse().unwrap();
+        let b: Grid<char> = "#.\n.#".parse().unwrap();
+        let c: Grid<char> = "##\n.#".parse().unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let grids = vec![a.clone(), b.clone(), c.clone()]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(grids.len(), 2);
+        let packed = [a, b, c]
+            .iter()
+            .map(|g| g.to_bits(|c| *c == '#'))
+            .collect::<HashSet<_>>();
+        assert_eq!(packed.len(), 2);
+    }
+}
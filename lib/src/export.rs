@@ -0,0 +1,285 @@
+//! Renders grids as images, one pixel per cell unless scaled up.
+//!
+//! PPM is always available. PNG and animated GIF output need the `png` and `gif` features.
+
+use crate::{Grid, SparseGrid};
+#[cfg(any(feature = "png", feature = "gif"))]
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// Cells which know how they should look in an image.
+pub trait Colour {
+    fn colour(&self) -> Rgb;
+}
+
+impl Colour for bool {
+    fn colour(&self) -> Rgb {
+        if *self {
+            WHITE
+        } else {
+            BLACK
+        }
+    }
+}
+
+/// The pixels of a rendered grid, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    /// Blows every pixel up into a `factor` x `factor` square.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let pixels = (0..width * height)
+            .map(|index| {
+                let (x, y) = (index % width / factor, index / width / factor);
+                self.pixels[y * self.width + x]
+            })
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Writes a binary PPM (P6), which most image viewers can open.
+    pub fn write_ppm<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.pixels.concat())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, writer: W) -> io::Result<()> {
+        let (width, height) = (dimension(self.width)?, dimension(self.height)?);
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels.concat())
+            .map_err(io::Error::other)
+    }
+
+    /// Writes to `path`, picking the format by its extension: `ppm`, or `png` and `gif` with
+    /// their features. Other extensions are `Unsupported` and leave the file untouched.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let create = || File::create(path).map(BufWriter::new);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ppm") => self.write_ppm(create()?),
+            #[cfg(feature = "png")]
+            Some("png") => self.write_png(create()?),
+            #[cfg(feature = "gif")]
+            Some("gif") => write_gif(std::slice::from_ref(self), 0, create()?),
+            _ => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "cannot save {}, use .ppm or enable the feature for its format",
+                    path.display()
+                ),
+            )),
+        }
+    }
+}
+
+/// Converts a width or height for formats which store it in fewer bits than `usize`.
+#[cfg(any(feature = "png", feature = "gif"))]
+fn dimension<D: TryFrom<usize>>(size: usize) -> io::Result<D> {
+    D::try_from(size).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} pixels are too many for this format", size),
+        )
+    })
+}
+
+/// Writes `frames` as a looping GIF, showing each one for `delay` hundredths of a second.
+/// Every frame must have the same size as the first one.
+#[cfg(feature = "gif")]
+pub fn write_gif<W: Write>(frames: &[Image], delay: u16, writer: W) -> io::Result<()> {
+    let (width, height) = frames.first().map_or((0, 0), |f| (f.width, f.height));
+    let (gif_width, gif_height) = (dimension(width)?, dimension(height)?);
+    let mut encoder =
+        gif::Encoder::new(writer, gif_width, gif_height, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+
+    for image in frames {
+        if (image.width, image.height) != (width, height) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "all frames of an animation must have the same size",
+            ));
+        }
+        let mut frame = gif::Frame::from_rgb(gif_width, gif_height, &image.pixels.concat());
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
+
+impl<T: Debug> Grid<T> {
+    /// Renders every cell with the colour picked by `palette`.
+    pub fn to_image_with<F: Fn(&T) -> Rgb>(&self, palette: F) -> Image {
+        Image {
+            width: self.width(),
+            height: self.height(),
+            pixels: self.as_slice().iter().map(palette).collect(),
+        }
+    }
+}
+
+impl<T: Debug + Colour> Grid<T> {
+    pub fn to_image(&self) -> Image {
+        self.to_image_with(T::colour)
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Renders the bounds of the grid, unset cells get the `background` colour.
+    pub fn to_image_with<F: Fn(&T) -> Rgb>(&self, background: Rgb, palette: F) -> Image {
+        let (width, height) = self.bounds().map_or((0, 0), |(x, y)| {
+            (
+                (x.end() - x.start() + 1) as usize,
+                (y.end() - y.start() + 1) as usize,
+            )
+        });
+        Image {
+            width,
+            height,
+            pixels: self
+                .iter()
+                .map(|item| item.element.map_or(background, &palette))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Colour> SparseGrid<T> {
+    pub fn to_image(&self, background: Rgb) -> Image {
+        self.to_image_with(background, T::colour)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::{Rgb, BLACK, WHITE};
+    use crate::{Grid, SparseGrid};
+    use std::io;
+    use std::{env, fs};
+
+    const RED: Rgb = [255, 0, 0];
+
+    #[test]
+    fn test_ppm() {
+        let grid: Grid<char> = "#.\n.#".parse().unwrap();
+        let image = grid.to_image_with(|c| if *c == '#' { WHITE } else { RED });
+        assert_eq!(image.pixels(), &[WHITE, RED, RED, WHITE]);
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+        assert_eq!(&ppm[..11], b"P6\n2 2\n255\n");
+        assert_eq!(&ppm[11..14], &WHITE);
+        assert_eq!(ppm.len(), 11 + 4 * 3);
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 4));
+        assert_eq!(&scaled.pixels()[..4], &[WHITE, WHITE, RED, RED]);
+        assert_eq!(&scaled.pixels()[12..], &[RED, RED, WHITE, WHITE]);
+    }
+
+    #[test]
+    fn test_save() {
+        let image = Grid::<bool>::new(2, 1, 0, 0).to_image();
+        let ppm = env::temp_dir().join("aoc-export-test.ppm");
+        image.save(&ppm).unwrap();
+        assert_eq!(&fs::read(&ppm).unwrap()[..3], b"P6\n");
+        fs::remove_file(&ppm).unwrap();
+
+        let mut unsupported = vec!["txt"];
+        if cfg!(not(feature = "png")) {
+            unsupported.push("png");
+        }
+        if cfg!(not(feature = "gif")) {
+            unsupported.push("gif");
+        }
+        for extension in unsupported {
+            let path = env::temp_dir().join(format!("aoc-export-test.{}", extension));
+            let error = image.save(&path).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+            assert!(!path.exists());
+        }
+    }
+
+    #[test]
+    fn test_sparse_grid_image() {
+        let mut grid = SparseGrid::new();
+        grid.set(-1, 0, true);
+        grid.set(1, 1, false);
+        let image = grid.to_image(RED);
+        assert_eq!((image.width(), image.height()), (3, 2));
+        assert_eq!(image.pixels(), &[WHITE, RED, RED, RED, RED, BLACK]);
+        assert!(SparseGrid::<bool>::new().to_image(RED).pixels().is_empty());
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_png() {
+        let grid: Grid<bool> = Grid::new(3, 2, 0, 0);
+        let mut png = vec![];
+        grid.to_image().write_png(&mut png).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn test_gif() {
+        use crate::export::write_gif;
+
+        let frames = [true, false]
+            .iter()
+            .map(|&on| {
+                Grid::<bool>::new(2, 2, 0, 0).to_image_with(|_| if on { RED } else { BLACK })
+            })
+            .collect::<Vec<_>>();
+        let mut gif = vec![];
+        write_gif(&frames, 10, &mut gif).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+
+        let mismatched = [frames[0].clone(), frames[0].scaled(2)];
+        assert!(write_gif(&mismatched, 10, &mut vec![]).is_err());
+
+        let wide = Grid::<bool>::new(65_536, 1, 0, 0).to_image();
+        let error = write_gif(&[wide], 10, &mut vec![]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
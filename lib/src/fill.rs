@@ -0,0 +1,131 @@
+use crate::search::Bfs;
+use crate::{Grid, Point, SparseGrid, NEIGHBOURS4};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+
+/// Steps from `start` to every position reachable through orthogonal moves onto open cells.
+fn flood<F: Fn(Point) -> bool>(start: Point, open: F) -> HashMap<Point, usize> {
+    if !open(start) {
+        return HashMap::new();
+    }
+    let successors = |&(x, y): &Point| {
+        NEIGHBOURS4
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&position| open(position))
+            .collect::<Vec<_>>()
+    };
+    Bfs::new(start, successors).collect()
+}
+
+/// Groups the open cells among `cells` into orthogonally connected regions, ordered by
+/// their first cell in `cells`.
+fn components<I, F>(cells: I, open: F) -> Vec<Vec<Point>>
+where
+    I: Iterator<Item = Point>,
+    F: Fn(Point) -> bool,
+{
+    let mut seen = HashSet::new();
+    let mut regions = vec![];
+    for position in cells {
+        if seen.contains(&position) || !open(position) {
+            continue;
+        }
+        let mut region = flood(position, &open).into_keys().collect::<Vec<_>>();
+        region.sort_by_key(|&(x, y)| (y, x));
+        seen.extend(region.iter().copied());
+        regions.push(region);
+    }
+    regions
+}
+
+impl<T: Debug> Grid<T> {
+    /// The number of orthogonal steps from `start` to every cell reachable over `passable`
+    /// cells, empty if `start` itself is not passable.
+    pub fn flood_fill<P: Fn(&T) -> bool>(
+        &self,
+        start: Point,
+        passable: P,
+    ) -> HashMap<Point, usize> {
+        flood(start, |(x, y)| self.get(x, y).is_some_and(&passable))
+    }
+
+    /// The orthogonally connected regions of cells matching `predicate`, each sorted in
+    /// row-major order.
+    pub fn connected_components<P: Fn(&T) -> bool>(&self, predicate: P) -> Vec<Vec<Point>> {
+        let open = |(x, y)| self.get(x, y).is_some_and(&predicate);
+        components(self.iter().map(|(position, _)| position), open)
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Like `Grid::flood_fill`, unset cells are never passable.
+    pub fn flood_fill<P: Fn(&T) -> bool>(
+        &self,
+        start: Point,
+        passable: P,
+    ) -> HashMap<Point, usize> {
+        flood(start, |(x, y)| self.get(x, y).is_some_and(&passable))
+    }
+
+    /// Like `Grid::connected_components`, unset cells belong to no region.
+    pub fn connected_components<P: Fn(&T) -> bool>(&self, predicate: P) -> Vec<Vec<Point>> {
+        let mut cells = self
+            .cells()
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        cells.sort_by_key(|&(x, y)| (y, x));
+        let open = |(x, y)| self.get(x, y).is_some_and(&predicate);
+        components(cells.into_iter(), open)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, SparseGrid};
+
+    #[test]
+    fn test_flood_fill() {
+        let grid: Grid<char> = "#####\n#..##\n#.#.#\n#...#\n#####".parse().unwrap();
+        let distances = grid.flood_fill((1, 1), |c| *c == '.');
+        assert_eq!(distances.len(), 7);
+        assert_eq!(distances[&(1, 1)], 0);
+        assert_eq!(distances[&(3, 2)], 5);
+        assert_eq!(distances.values().max(), Some(&5));
+
+        assert!(grid.flood_fill((0, 0), |c| *c == '.').is_empty());
+        assert!(grid.flood_fill((-1, 7), |c| *c == '.').is_empty());
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let grid: Grid<char> = "##.#\n#..#\n.#.#".parse().unwrap();
+        let walls = grid.connected_components(|c| *c == '#');
+        assert_eq!(
+            walls,
+            vec![
+                vec![(0, 0), (1, 0), (0, 1)],
+                vec![(3, 0), (3, 1), (3, 2)],
+                vec![(1, 2)],
+            ]
+        );
+        assert_eq!(grid.connected_components(|c| *c == '.').len(), 2);
+    }
+
+    #[test]
+    fn test_sparse_grid_fill() {
+        let mut grid = SparseGrid::new();
+        for x in -2..=2 {
+            grid.set(x, 0, x != 0);
+        }
+        grid.set(5, 5, true);
+
+        let distances = grid.flood_fill((-2, 0), |open| *open);
+        assert_eq!(distances.len(), 2);
+        assert_eq!(distances.get(&(-1, 0)), Some(&1));
+        assert_eq!(
+            grid.connected_components(|open| *open),
+            vec![vec![(-2, 0), (-1, 0)], vec![(1, 0), (2, 0)], vec![(5, 5)]]
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Points, vectors and compass directions.
+//!
+//! Coordinates follow the grids: `x` grows to the east and `y` grows to the south, so
+//! `Direction::North` is a step towards smaller `y`.
+
+use crate::Point;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed integers usable as coordinates.
+pub trait Coordinate: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {
+    fn abs(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A position or vector in the plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A position or vector in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    /// The sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The largest distance along any axis, the number of king moves between the points.
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl Point2<isize> {
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The four orthogonal neighbours in the order of `Direction::ALL`.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .iter()
+            .map(move |&direction| self.step(direction))
+    }
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    /// The sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The largest distance along any axis.
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+macro_rules! operators {
+    ($point:ident, $($axis:ident),*) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),* }
+            }
+        }
+
+        /// Scales every axis.
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($axis: self.$axis * factor),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$axis += other.$axis;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$axis -= other.$axis;)*
+            }
+        }
+    };
+}
+
+operators!(Point2, x, y);
+operators!(Point3, x, y, z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+/// The four compass directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    /// The vector of a single step, matching `NEIGHBOURS4`.
+    pub fn offset(self) -> Point2<isize> {
+        match self {
+            Direction::North => Point2::new(0, -1),
+            Direction::East => Point2::new(1, 0),
+            Direction::South => Point2::new(0, 1),
+            Direction::West => Point2::new(-1, 0),
+        }
+    }
+}
+
+impl Add<Direction> for Point2<isize> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self.step(direction)
+    }
+}
+
+impl AddAssign<Direction> for Point2<isize> {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = self.step(direction);
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.offset().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geom::{Direction, Point2, Point3};
+    use crate::{Grid, NEIGHBOURS4};
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 3, Point2::new(-3, 6));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+
+        let mut c = Point3::new(1i32, 2, 3);
+        c += Point3::new(1, 1, 1);
+        assert_eq!(c, Point3::from((2, 3, 4)));
+        assert_eq!(c.manhattan(Point3::default()), 9);
+        assert_eq!(c.chebyshev(Point3::default()), 4);
+
+        let tuple: (isize, isize) = a.into();
+        assert_eq!(Point2::from(tuple), a);
+    }
+
+    #[test]
+    fn test_directions() {
+        let mut facing = Direction::North;
+        for expected in [Direction::East, Direction::South, Direction::West] {
+            facing = facing.turn_right();
+            assert_eq!(facing, expected);
+        }
+        assert_eq!(facing.turn_left(), Direction::South);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+
+        let offsets = Direction::ALL.map(<(isize, isize)>::from);
+        assert_eq!(offsets, NEIGHBOURS4);
+
+        let mut position = Point2::new(0, 0);
+        position += Direction::South;
+        assert_eq!(position + Direction::East, Point2::new(1, 1));
+        assert_eq!(position.neighbours().count(), 4);
+    }
+
+    #[test]
+    fn test_grid_indexing() {
+        let mut grid: Grid<char> = "ab\ncd".parse().unwrap();
+        let position = Point2::new(1, 0);
+        assert_eq!(grid[position], 'b');
+        grid[position + Direction::South] = 'x';
+        assert_eq!(grid[(1, 1)], 'x');
+
+        assert_eq!(grid.get_at(position), Some(&'b'));
+        assert!(grid.contains_at(position));
+        assert!(!grid.contains_at(position + Direction::North));
+        grid.set_at(position, 'y').unwrap();
+        *grid.get_mut_at(Point2::new(0, 0)).unwrap() = 'z';
+        assert_eq!(grid.to_text(), "zy\ncx\n");
+        assert!(grid.set_at(Point2::new(2, 0), 'w').is_err());
+        assert_eq!(
+            grid.neighbours4_at(position).collect::<Vec<_>>(),
+            vec![((1, 1), &'x'), ((0, 0), &'z')]
+        );
+        assert_eq!(grid.neighbours8_at(position).count(), 3);
+    }
+}
@@ -0,0 +1,187 @@
+//! Reads puzzle inputs from files or stdin and splits them into their usual shapes.
+//!
+//! Every day looks for its input in `inputs/dayNN.txt` unless given another path, so that
+//! everybody can solve their own inputs without touching the code.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fmt, fs};
+
+/// The directory with the default inputs, relative to the working directory.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `inputs/dayNN.txt`.
+    pub fn default_for(day: u8) -> Self {
+        InputSource::File(Path::new(INPUT_DIR).join(format!("day{:02}.txt", day)))
+    }
+
+    /// A path given on the command line, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let mut input = String::new();
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path).map(|text| input = text),
+            InputSource::Stdin => io::stdin().read_to_string(&mut input).map(|_| ()),
+        };
+        result.map(|_| input).map_err(|error| InputError {
+            from: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// An input which could not be read, together with where it was read from.
+#[derive(Debug)]
+pub struct InputError {
+    from: InputSource,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not read {}: {}", self.from, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reads the input of `day` from the path given as the first command line argument, or from
+/// `inputs/dayNN.txt` without one.
+pub fn from_args(day: u8) -> Result<String, InputError> {
+    match env::args().nth(1) {
+        Some(arg) => InputSource::from_arg(&arg).read(),
+        None => InputSource::default_for(day).read(),
+    }
+}
+
+/// A short name for `input` which stays the same across line endings and trailing whitespace,
+/// to tell apart the inputs of different people.
+pub fn input_id(input: &str) -> String {
+    // 64 bit FNV-1a, because the hashers of std may change between releases.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for line in input.trim_end().lines() {
+        for byte in line.trim_end().bytes().chain(Some(b'\n')) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// The non-empty lines of `input`, without surrounding whitespace.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Parses every non-empty line of `input`, naming the first value that is malformed.
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, String>
+where
+    T::Err: Display,
+{
+    lines(input).map(parse_value).collect()
+}
+
+/// Parses a comma separated list like `1,-2, 3`, as used by intcode programs.
+pub fn comma_separated<T: FromStr>(input: &str) -> Result<Vec<T>, String>
+where
+    T::Err: Display,
+{
+    input
+        .trim()
+        .split(',')
+        .map(|value| parse_value(value.trim()))
+        .collect()
+}
+
+fn parse_value<T: FromStr>(value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|error| format!("Could not parse '{}': {}", value, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{comma_separated, input_id, lines, parse_lines, InputSource};
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_splitting() {
+        assert_eq!(comma_separated::<isize>("1,-2, 3\n"), Ok(vec![1, -2, 3]));
+        assert_eq!(
+            comma_separated::<isize>("1,,2"),
+            Err(String::from(
+                "Could not parse '': cannot parse integer from empty string"
+            ))
+        );
+        assert_eq!(
+            lines("  COM)B\r\nB)C\n\n").collect::<Vec<_>>(),
+            vec!["COM)B", "B)C"]
+        );
+        assert_eq!(parse_lines::<u32>("12\n14\n"), Ok(vec![12, 14]));
+        assert!(parse_lines::<u32>("12\nx\n").is_err());
+    }
+
+    #[test]
+    fn test_input_id() {
+        assert_eq!(input_id(""), "cbf29ce484222325");
+        assert_eq!(input_id("1,2,3\n"), input_id("1,2,3"));
+        assert_eq!(input_id("#.\r\n.#\r\n"), input_id("#.\n.#\n"));
+        assert_ne!(input_id("1,2,3"), input_id("1,2,4"));
+        assert_eq!(input_id("1,2,3").len(), 16);
+    }
+
+    #[test]
+    fn test_sources() {
+        assert_eq!(
+            InputSource::default_for(7),
+            InputSource::File(PathBuf::from("inputs/day07.txt"))
+        );
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+
+        let path = env::temp_dir().join("aoc-input-test.txt");
+        fs::write(&path, "1,2,3\n").unwrap();
+        let source = InputSource::from_arg(path.to_str().unwrap());
+        assert_eq!(source.read().unwrap(), "1,2,3\n");
+        fs::remove_file(&path).unwrap();
+
+        let error = source.read().unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&format!("Could not read {}: ", path.display())));
+    }
+}
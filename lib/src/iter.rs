@@ -0,0 +1,129 @@
+use crate::Point;
+use std::iter::Enumerate;
+use std::slice;
+
+pub(crate) fn index_to_point(index: usize, width: usize, origin: Point) -> Point {
+    let (min_x, min_y) = origin;
+    (
+        min_x + (index % width) as isize,
+        min_y + (index / width) as isize,
+    )
+}
+
+/// Borrows every cell of a `Grid` together with its position, see `Grid::iter`.
+pub struct Iter<'a, T> {
+    inner: Enumerate<slice::Iter<'a, T>>,
+    width: usize,
+    origin: Point,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(cells: &'a [T], width: usize, origin: Point) -> Self {
+        Iter {
+            inner: cells.iter().enumerate(),
+            width,
+            origin,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, cell) = self.inner.next()?;
+        Some((index_to_point(index, self.width, self.origin), cell))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Mutably borrows every cell of a `Grid` together with its position, see `Grid::iter_mut`.
+pub struct IterMut<'a, T> {
+    inner: Enumerate<slice::IterMut<'a, T>>,
+    width: usize,
+    origin: Point,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(cells: &'a mut [T], width: usize, origin: Point) -> Self {
+        IterMut {
+            inner: cells.iter_mut().enumerate(),
+            width,
+            origin,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (Point, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, cell) = self.inner.next()?;
+        Some((index_to_point(index, self.width, self.origin), cell))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+
+    #[test]
+    fn test_borrowing_iterators() {
+        let mut grid: Grid<isize> = Grid::new(3, 2, 1, 0);
+        for ((x, y), cell) in grid.iter_mut() {
+            *cell = x + 10 * y;
+        }
+
+        let cells = grid.iter().map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+        assert_eq!(cells[0], ((-1, 0), -1));
+        assert_eq!(cells[5], ((1, 1), 11));
+        assert_eq!(grid.iter().len(), 6);
+
+        let rows = grid.rows().collect::<Vec<_>>();
+        assert_eq!(rows, vec![&[-1, 0, 1][..], &[9, 10, 11][..]]);
+
+        let columns = grid
+            .columns()
+            .map(|c| c.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec![vec![-1, 9], vec![0, 10], vec![1, 11]]);
+
+        let rect = grid
+            .cells_in_rect(0..=5, -3..=1)
+            .map(|(p, c)| (p, *c))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rect,
+            vec![((0, 0), 0), ((1, 0), 1), ((0, 1), 10), ((1, 1), 11)]
+        );
+        assert_eq!(grid.cells_in_rect(5..=6, 0..=1).count(), 0);
+
+        let odd = grid.positions_of(|c| c % 2 != 0).collect::<Vec<_>>();
+        assert_eq!(odd, vec![(-1, 0), (1, 0), (-1, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_iterators_without_clone() {
+        #[derive(Debug, Default)]
+        struct Opaque(u8);
+
+        let mut grid: Grid<Opaque> = Grid::new(2, 2, 0, 0);
+        grid.get_mut(1, 1).unwrap().0 = 7;
+        assert_eq!(
+            grid.positions_of(|c| c.0 == 7).collect::<Vec<_>>(),
+            vec![(1, 1)]
+        );
+        assert_eq!(grid.into_iter().map(|item| item.element.0).sum::<u8>(), 7);
+    }
+}
@@ -1,134 +1,220 @@
 use std::fmt::{Debug, Display, Error, Formatter};
-use std::ops::Add;
+use std::iter::Enumerate;
+use std::ops::{Add, Index, IndexMut, RangeInclusive};
+use std::vec;
 
-#[derive(Debug, Clone)]
+pub mod automaton;
+mod bits;
+pub mod export;
+mod fill;
+pub mod geom;
+pub mod input;
+mod iter;
+pub mod log;
+mod neighbours;
+mod parse;
+#[cfg(feature = "serde")]
+mod schema;
+pub mod search;
+pub mod solution;
+mod sparse;
+pub mod term;
+mod transform;
+
+pub use crate::bits::BitGrid;
+pub use crate::iter::{Iter, IterMut};
+pub use crate::neighbours::{Topology, NEIGHBOURS4, NEIGHBOURS8};
+pub use crate::parse::{CharCell, ParseGridError};
+pub use crate::sparse::{SparseGrid, SparseGridIterator};
+pub use crate::transform::{Rect, SubGrid};
+
+/// A cell position as `(x, y)`.
+pub type Point = (isize, isize);
+
+/// A dense, fixed-size grid covering the cells from `(min_x, min_y)` to `(max_x, max_y)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T: Debug> {
     grid: Vec<T>,
     grid_size: (usize, usize),
-    offset: usize,
+    origin: (isize, isize),
 }
 
+/// Renders one line per row through `T: Display`, without a trailing newline. `CharCell` maps
+/// render the exact text they were parsed from with `Grid::to_text`.
 impl<T: Display + Debug> Display for Grid<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         let mut output = String::from("");
-        let (x, y) = self.grid_size;
 
-        for col in (0..y).into_iter() {
-            for row in (0..x).into_iter() {
-                let item = self.grid.get(col * x + row).unwrap();
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                output = output.add("\n");
+            }
+            for item in row {
                 output = output.add(format!("{}", item).as_str());
             }
-            output = output.add("\n");
         }
 
-        write!(f, "\n{}", output)
+        write!(f, "{}", output)
     }
 }
 
 impl<T: Default + Debug> Grid<T> {
+    /// Creates a `width` x `height` grid, where `(0, 0)` is the cell `x_offset` columns from
+    /// the left and `y_offset` rows from the top edge.
     pub fn new(width: usize, height: usize, x_offset: usize, y_offset: usize) -> Grid<T> {
-        let mut data = Vec::with_capacity(width * height);
+        let min_x = -(x_offset as isize);
+        let min_y = -(y_offset as isize);
+        Grid::with_bounds(
+            min_x..=min_x + width as isize - 1,
+            min_y..=min_y + height as isize - 1,
+        )
+    }
 
-        for i in 0..width * height {
-            data.insert(i, T::default())
-        }
+    /// Creates a grid covering exactly the given (inclusive) coordinate ranges.
+    pub fn with_bounds(x: RangeInclusive<isize>, y: RangeInclusive<isize>) -> Grid<T> {
+        let width = (x.end() - x.start() + 1).max(0) as usize;
+        let height = (y.end() - y.start() + 1).max(0) as usize;
+        let mut data = Vec::with_capacity(width * height);
+        data.resize_with(width * height, T::default);
 
         Grid {
             grid: data,
             grid_size: (width, height),
-            offset: y_offset * height + x_offset,
+            origin: (*x.start(), *y.start()),
         }
     }
 }
 
 impl<T: Debug> Grid<T> {
-    fn coords_to_index(&self, x: isize, y: isize) -> usize {
-        ((y * self.grid_size.0 as isize + x) + self.offset as isize) as usize
+    fn coords_to_index(&self, x: isize, y: isize) -> Option<usize> {
+        if !self.check_bounds(x, y) {
+            return None;
+        }
+        let (min_x, min_y) = self.origin;
+        Some((y - min_y) as usize * self.grid_size.0 + (x - min_x) as usize)
+    }
+
+    fn check_bounds(&self, x: isize, y: isize) -> bool {
+        self.x_range().contains(&x) && self.y_range().contains(&y)
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.check_bounds(x, y)
+    }
+
+    /// Like `contains`, for `(x, y)` tuples or `geom::Point2`.
+    pub fn contains_at<P: Into<Point>>(&self, position: P) -> bool {
+        let (x, y) = position.into();
+        self.contains(x, y)
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid_size.0
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid_size.1
+    }
+
+    pub fn min_x(&self) -> isize {
+        self.origin.0
+    }
+
+    pub fn max_x(&self) -> isize {
+        self.origin.0 + (self.grid_size.0 as isize - 1)
+    }
+
+    pub fn min_y(&self) -> isize {
+        self.origin.1
+    }
+
+    pub fn max_y(&self) -> isize {
+        self.origin.1 + (self.grid_size.1 as isize - 1)
+    }
+
+    pub fn x_range(&self) -> RangeInclusive<isize> {
+        self.min_x()..=self.max_x()
     }
 
-    pub fn get_row(&self, row_idx: usize) -> &[T] {
-        let (width, height) = self.grid_size;
-        let offset = row_idx * width;
-        &self.grid[offset..offset + width]
+    pub fn y_range(&self) -> RangeInclusive<isize> {
+        self.min_y()..=self.max_y()
+    }
+
+    /// Returns the cells of row `y`, from `min_x` to `max_x`.
+    pub fn get_row(&self, y: isize) -> Option<&[T]> {
+        let offset = self.coords_to_index(self.min_x(), y)?;
+        Some(&self.grid[offset..offset + self.grid_size.0])
     }
 }
 
 pub struct GridIntoIterator<T: Debug> {
-    grid: Grid<T>,
-    x: isize,
-    y: isize,
+    inner: Enumerate<vec::IntoIter<T>>,
+    width: usize,
+    origin: Point,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GridIteratorItem<T> {
     pub element: T,
     pub x: isize,
     pub y: isize,
 }
 
-impl<T: Debug + Clone> IntoIterator for Grid<T> {
+impl<T: Debug> IntoIterator for Grid<T> {
     type Item = GridIteratorItem<T>;
     type IntoIter = GridIntoIterator<T>;
 
     fn into_iter(self) -> Self::IntoIter {
         GridIntoIterator {
-            grid: self,
-            x: 0,
-            y: 0,
+            inner: self.grid.into_iter().enumerate(),
+            width: self.grid_size.0,
+            origin: self.origin,
         }
     }
 }
 
-impl<T: Debug + Clone> Iterator for GridIntoIterator<T> {
+impl<T: Debug> Iterator for GridIntoIterator<T> {
     type Item = GridIteratorItem<T>;
     fn next(&mut self) -> Option<GridIteratorItem<T>> {
-        if self.x == self.grid.grid_size.0 as isize {
-            self.x = 0;
-            self.y += 1;
-            if self.y == self.grid.grid_size.1 as isize {
-                return None;
-            }
-        }
+        let (index, element) = self.inner.next()?;
+        let (x, y) = iter::index_to_point(index, self.width, self.origin);
+        Some(GridIteratorItem { element, x, y })
+    }
+}
 
-        let index = self.grid.coords_to_index(self.x, self.y);
-        let cell = self.grid.grid.get(index).map_or(None, |x| {
-            Some(GridIteratorItem {
-                x: self.x,
-                y: self.y,
-                element: x.clone(),
-            })
-        });
+impl<'a, T: Debug> IntoIterator for &'a Grid<T> {
+    type Item = (Point, &'a T);
+    type IntoIter = Iter<'a, T>;
 
-        self.x += 1;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
-        cell
+impl<'a, T: Debug> IntoIterator for &'a mut Grid<T> {
+    type Item = (Point, &'a mut T);
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
-impl<T: Debug + Clone> Grid<T> {
+impl<T: Debug> Grid<T> {
     pub fn get(&self, x: isize, y: isize) -> Option<&T> {
-        if !self.check_bounds(x, y) {
-            return None;
-        }
-        let index = self.coords_to_index(x, y) as usize;
+        let index = self.coords_to_index(x, y)?;
         self.grid.get(index)
     }
 
-    fn check_bounds(&self, x: isize, y: isize) -> bool {
-        let (width, height) = self.grid_size;
-        if self.offset == 0 {
-            x < width as isize && y < height as isize && x >= 0 && y >= 0
-        } else {
-            true
-        }
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let index = self.coords_to_index(x, y)?;
+        self.grid.get_mut(index)
     }
 
+    #[allow(clippy::result_unit_err)]
     pub fn set(&mut self, x: isize, y: isize, item: T) -> Result<(), ()> {
-        if !self.check_bounds(x, y) {
-            return Err(());
-        }
-        let index = self.coords_to_index(x, y) as usize;
-        match self.grid.get_mut(index) {
+        match self.get_mut(x, y) {
             Some(old_item) => {
                 *old_item = item;
                 Ok(())
@@ -137,16 +223,96 @@ impl<T: Debug + Clone> Grid<T> {
         }
     }
 
-    pub fn iter(&self) -> GridIntoIterator<T> {
-        GridIntoIterator {
-            grid: self.clone(),
-            x: 0,
-            y: 0,
-        }
+    /// Like `get`, for `(x, y)` tuples or `geom::Point2`.
+    pub fn get_at<P: Into<Point>>(&self, position: P) -> Option<&T> {
+        let (x, y) = position.into();
+        self.get(x, y)
+    }
+
+    pub fn get_mut_at<P: Into<Point>>(&mut self, position: P) -> Option<&mut T> {
+        let (x, y) = position.into();
+        self.get_mut(x, y)
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn set_at<P: Into<Point>>(&mut self, position: P, item: T) -> Result<(), ()> {
+        let (x, y) = position.into();
+        self.set(x, y, item)
+    }
+
+    /// All cells in row-major order, starting at `(min_x, min_y)`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.grid, self.grid_size.0, self.origin)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.grid, self.grid_size.0, self.origin)
+    }
+
+    /// The rows from `min_y` to `max_y`.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.grid.chunks(self.grid_size.0.max(1))
+    }
+
+    /// The columns from `min_x` to `max_x`, each one iterating from `min_y` to `max_y`.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let width = self.grid_size.0;
+        (0..width).map(move |x| self.grid.iter().skip(x).step_by(width))
+    }
+
+    /// The cells within the given ranges, clipped to the bounds of the grid.
+    pub fn cells_in_rect(
+        &self,
+        x: RangeInclusive<isize>,
+        y: RangeInclusive<isize>,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        let x = *x.start().max(&self.min_x())..=*x.end().min(&self.max_x());
+        let y = *y.start().max(&self.min_y())..=*y.end().min(&self.max_y());
+        y.flat_map(move |y| {
+            let offset = self.coords_to_index(*x.start(), y);
+            let cells = offset.map_or(&[][..], |offset| {
+                &self.grid[offset..offset + x.clone().count()]
+            });
+            x.clone().zip(cells).map(move |(x, cell)| ((x, y), cell))
+        })
+    }
+
+    /// The positions of all cells matching `predicate`, in row-major order.
+    pub fn positions_of<'a, P: Fn(&T) -> bool + 'a>(
+        &'a self,
+        predicate: P,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
     }
 
-    pub fn grid(&self) -> Vec<T> {
-        self.grid.clone()
+    pub fn as_slice(&self) -> &[T] {
+        &self.grid
+    }
+
+    /// The cells row by row, the same as `as_slice`.
+    pub fn grid(&self) -> &[T] {
+        &self.grid
+    }
+}
+
+/// Indexes by `(x, y)` tuples or `geom::Point2`, panicking outside of the grid.
+impl<T: Debug, P: Into<Point>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &T {
+        let (x, y) = position.into();
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y))
+    }
+}
+
+impl<T: Debug, P: Into<Point>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut T {
+        let (x, y) = position.into();
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y))
     }
 }
 
@@ -173,12 +339,64 @@ mod tests {
 
         grid.set(-2, -2, true).unwrap();
         assert_eq!(&true, grid.get(-2, -2).unwrap());
+
+        assert_eq!(None, grid.get(-3, 0));
+        assert_eq!(None, grid.get(0, -3));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, 3));
+        assert_eq!(&false, grid.get(2, 2).unwrap());
+    }
+
+    #[test]
+    fn test_non_square_offset_grid() {
+        let mut grid: Grid<usize> = Grid::new(4, 2, 1, 1);
+        assert_eq!((grid.min_x(), grid.max_x()), (-1, 2));
+        assert_eq!((grid.min_y(), grid.max_y()), (-1, 0));
+
+        let cells = grid
+            .iter()
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        for (i, (x, y)) in cells.iter().enumerate() {
+            grid.set(*x, *y, i).unwrap();
+        }
+        assert_eq!(grid.get_row(-1).unwrap(), &[0, 1, 2, 3]);
+        assert_eq!(grid.get_row(0).unwrap(), &[4, 5, 6, 7]);
+        assert_eq!(grid.get_row(1), None);
+        assert_eq!(format!("{}", grid), "0123\n4567");
+    }
+
+    #[test]
+    fn test_negative_bounds_on_every_edge() {
+        let mut grid: Grid<bool> = Grid::with_bounds(-5..=-2, -3..=1);
+        assert_eq!((grid.width(), grid.height()), (4, 5));
+
+        // Corners are inside, one step beyond any edge is outside
+        for &(x, y) in &[(-5, -3), (-2, -3), (-5, 1), (-2, 1)] {
+            assert!(grid.contains(x, y));
+            grid.set(x, y, true).unwrap();
+            assert_eq!(&true, grid.get(x, y).unwrap());
+        }
+        for &(x, y) in &[(-6, -1), (-1, -1), (-3, -4), (-3, 2), (0, 0)] {
+            assert!(!grid.contains(x, y));
+            assert_eq!(None, grid.get(x, y));
+            assert_eq!(Err(()), grid.set(x, y, true));
+        }
+
+        assert_eq!(grid.get_row(-4), None);
+        assert_eq!(grid.get_row(-3).unwrap(), &[true, false, false, true]);
+        assert_eq!(grid.get_row(1).unwrap(), &[true, false, false, true]);
+        assert_eq!(grid.get_row(2), None);
+
+        let first = grid.iter().next().unwrap();
+        assert_eq!(first.0, (-5, -3));
+        assert_eq!(grid.iter().count(), 20);
     }
 
     #[test]
     fn test_iterator_length() {
         let grid: Grid<bool> = Grid::new(5, 5, 0, 0);
-        assert_eq!(grid.iter().map(|_| 1).count(), 25);
+        assert_eq!(grid.iter().count(), 25);
         assert_eq!(grid.into_iter().count(), 25);
     }
 }
@@ -0,0 +1,306 @@
+//! Diagnostics for solutions, written to stderr so that stdout only carries answers.
+//!
+//! Messages are logged with the `error!`, `warn!`, `info!`, `debug!` and `trace!` macros and
+//! shown when the global `Filter` allows their level in the module they come from. A filter is
+//! written like `warn,day_07=trace,intcode=off`: a default level followed by module overrides,
+//! where the longest matching module wins.
+
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+/// The environment variable the filter of `init_from_env` is read from.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// How important a message is, from the most to the least important.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    /// The level `steps` more verbose than this one, stopping at `Trace`.
+    pub fn more_verbose(self, steps: usize) -> Level {
+        let index = Level::ALL.iter().position(|&level| level == self).unwrap();
+        Level::ALL[(index + steps).min(Level::ALL.len() - 1)]
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parses a level, where `off` turns off all messages.
+fn parse_level(s: &str) -> Result<Option<Level>, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "warn" => Ok(Some(Level::Warn)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(format!("Unknown log level '{}'", s)),
+    }
+}
+
+/// The most verbose level shown in each module, `None` showing nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    modules: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    pub const fn new(default: Option<Level>) -> Self {
+        Filter {
+            default,
+            modules: Vec::new(),
+        }
+    }
+
+    /// Overrides the level of `module` and the modules inside it.
+    pub fn module(mut self, module: &str, level: Option<Level>) -> Self {
+        self.modules.retain(|(name, _)| name != module);
+        self.modules.push((module.to_string(), level));
+        self
+    }
+
+    /// Replaces the default level, keeping the module overrides.
+    pub fn default_level(mut self, level: Option<Level>) -> Self {
+        self.default = level;
+        self
+    }
+
+    /// Shows `steps` more levels by default, keeping the module overrides.
+    pub fn more_verbose(mut self, steps: usize) -> Self {
+        self.default = match (self.default, steps) {
+            (default, 0) => default,
+            (Some(level), steps) => Some(level.more_verbose(steps)),
+            (None, steps) => Some(Level::Error.more_verbose(steps - 1)),
+        };
+        self
+    }
+
+    pub fn level(&self, module: &str) -> Option<Level> {
+        self.modules
+            .iter()
+            .filter(|(name, _)| {
+                module == name
+                    || (module.starts_with(name.as_str()) && module[name.len()..].starts_with("::"))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    pub fn enabled(&self, module: &str, level: Level) -> bool {
+        self.level(module).is_some_and(|shown| level <= shown)
+    }
+
+    /// The most verbose level shown in any module.
+    fn max_level(&self) -> Option<Level> {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .chain(Some(self.default))
+            .max()
+            .flatten()
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::new(Some(Level::Warn))
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            filter = match directive.split_once('=') {
+                Some((module, level)) => filter.module(module.trim(), parse_level(level.trim())?),
+                None => filter.default_level(parse_level(directive)?),
+            };
+        }
+        Ok(filter)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Some(Level::Warn)));
+/// The most verbose level of `FILTER`, to skip the lock for most messages.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_filter(filter: Filter) {
+    let max_level = filter.max_level().map_or(0, |level| level as u8);
+    *FILTER.write().unwrap() = filter;
+    MAX_LEVEL.store(max_level, Ordering::Relaxed);
+}
+
+pub fn filter() -> Filter {
+    FILTER.read().unwrap().clone()
+}
+
+/// Reads the filter from `AOC_LOG` if it is set, keeping the current one otherwise.
+pub fn init_from_env() -> Result<(), String> {
+    if let Ok(spec) = env::var(ENV_VAR) {
+        set_filter(
+            spec.parse()
+                .map_err(|error| format!("{}: {}", ENV_VAR, error))?,
+        );
+    }
+    Ok(())
+}
+
+/// Whether messages of `level` from `module` are shown, to skip expensive diagnostics.
+pub fn enabled(module: &str, level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && FILTER.read().unwrap().enabled(module, level)
+}
+
+/// Writes a message the macros have already checked with `enabled`, so that their arguments
+/// are only evaluated when the message is shown.
+#[doc(hidden)]
+pub fn log(level: Level, module: &str, message: fmt::Arguments<'_>) {
+    eprintln!("[{} {}] {}", level, module, message);
+}
+
+/// Whether messages of the given level are shown in the calling module.
+#[macro_export]
+macro_rules! log_enabled {
+    ($level:expr) => {
+        $crate::log::enabled(module_path!(), $level)
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $crate::log::Level::Error) {
+            $crate::log::log($crate::log::Level::Error, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $crate::log::Level::Warn) {
+            $crate::log::log($crate::log::Level::Warn, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $crate::log::Level::Info) {
+            $crate::log::log($crate::log::Level::Info, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $crate::log::Level::Debug) {
+            $crate::log::log($crate::log::Level::Debug, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        if $crate::log::enabled(module_path!(), $crate::log::Level::Trace) {
+            $crate::log::log($crate::log::Level::Trace, module_path!(), format_args!($($arg)+))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::log::{Filter, Level};
+
+    #[test]
+    fn test_filter_modules() {
+        let filter = Filter::default()
+            .module("day_07", Some(Level::Trace))
+            .module("day_07::amplifier", None);
+        assert!(filter.enabled("day_12", Level::Warn));
+        assert!(!filter.enabled("day_12", Level::Info));
+        assert!(filter.enabled("day_07", Level::Trace));
+        assert!(filter.enabled("day_07::tests", Level::Trace));
+        assert!(!filter.enabled("day_07::amplifier", Level::Error));
+        assert!(!filter.enabled("day_070", Level::Info));
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+
+        let filter = filter.more_verbose(2);
+        assert!(filter.enabled("day_12", Level::Debug));
+        assert!(!filter.enabled("day_07::amplifier", Level::Error));
+        let filter = Filter::new(None).more_verbose(1);
+        assert_eq!(filter.level("day_12"), Some(Level::Error));
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = "info, day_07=trace,intcode=off".parse::<Filter>().unwrap();
+        assert_eq!(
+            filter,
+            Filter::new(Some(Level::Info))
+                .module("day_07", Some(Level::Trace))
+                .module("intcode", None)
+        );
+        assert_eq!("".parse(), Ok(Filter::default()));
+        assert_eq!(
+            "day_07=loud".parse::<Filter>(),
+            Err(String::from("Unknown log level 'loud'"))
+        );
+        assert_eq!("off".parse::<Filter>().unwrap().max_level(), None);
+    }
+
+    #[test]
+    fn test_verbosity() {
+        assert_eq!(Level::Warn.more_verbose(1), Level::Info);
+        assert_eq!(Level::Warn.more_verbose(7), Level::Trace);
+        assert_eq!(Level::Error.to_string(), "ERROR");
+    }
+
+    #[test]
+    fn test_skip_filtered_arguments() {
+        let mut evaluated = Vec::new();
+        crate::trace!("{}", {
+            evaluated.push(Level::Trace);
+            "hidden"
+        });
+        crate::error!("{}", {
+            evaluated.push(Level::Error);
+            "shown"
+        });
+        assert_eq!(evaluated, vec![Level::Error]);
+    }
+}
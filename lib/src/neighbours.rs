@@ -0,0 +1,146 @@
+use crate::{Grid, Point};
+use std::fmt::Debug;
+
+/// The orthogonal neighbours: north, east, south and west.
+pub const NEIGHBOURS4: [Point; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The orthogonal and diagonal neighbours, clockwise starting north.
+pub const NEIGHBOURS8: [Point; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// How neighbour lookups treat positions beyond the edges of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Neighbours outside of the grid are skipped.
+    Bounded,
+    /// Neighbours wrap around to the opposite edge.
+    Toroidal,
+}
+
+impl<T: Debug> Grid<T> {
+    /// The in-bounds orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: isize, y: isize) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours_with(x, y, &NEIGHBOURS4, Topology::Bounded)
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `(x, y)`.
+    pub fn neighbours8(&self, x: isize, y: isize) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours_with(x, y, &NEIGHBOURS8, Topology::Bounded)
+    }
+
+    /// Like `neighbours4`, for `(x, y)` tuples or `geom::Point2`.
+    pub fn neighbours4_at<P: Into<Point>>(&self, position: P) -> impl Iterator<Item = (Point, &T)> {
+        let (x, y) = position.into();
+        self.neighbours4(x, y)
+    }
+
+    /// Like `neighbours8`, for `(x, y)` tuples or `geom::Point2`.
+    pub fn neighbours8_at<P: Into<Point>>(&self, position: P) -> impl Iterator<Item = (Point, &T)> {
+        let (x, y) = position.into();
+        self.neighbours8(x, y)
+    }
+
+    /// The cells at each offset of `stencil` relative to `(x, y)`.
+    pub fn neighbours_with<'a>(
+        &'a self,
+        x: isize,
+        y: isize,
+        stencil: &'a [Point],
+        topology: Topology,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        stencil.iter().filter_map(move |(dx, dy)| {
+            let position = match topology {
+                Topology::Bounded => (x + dx, y + dy),
+                Topology::Toroidal => self.wrap(x + dx, y + dy)?,
+            };
+            self.get(position.0, position.1)
+                .map(|cell| (position, cell))
+        })
+    }
+
+    /// Counts the neighbours at the offsets of `stencil` which match `predicate`.
+    pub fn count_neighbours<P: Fn(&T) -> bool>(
+        &self,
+        x: isize,
+        y: isize,
+        stencil: &[Point],
+        topology: Topology,
+        predicate: P,
+    ) -> usize {
+        self.neighbours_with(x, y, stencil, topology)
+            .filter(|(_, cell)| predicate(cell))
+            .count()
+    }
+
+    /// Maps any position onto the grid as if its edges were connected, `None` if it is empty.
+    pub fn wrap(&self, x: isize, y: isize) -> Option<Point> {
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        Some((
+            self.min_x() + (x - self.min_x()).rem_euclid(width),
+            self.min_y() + (y - self.min_y()).rem_euclid(height),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Topology, NEIGHBOURS4, NEIGHBOURS8};
+
+    fn numbered_grid() -> Grid<isize> {
+        let mut grid = Grid::new(3, 3, 1, 1);
+        for ((x, y), cell) in grid.iter_mut() {
+            *cell = 3 * (y + 1) + (x + 1);
+        }
+        grid
+    }
+
+    #[test]
+    fn test_bounded_neighbours() {
+        let grid = numbered_grid();
+        let cells = |x, y| grid.neighbours4(x, y).map(|(_, c)| *c).collect::<Vec<_>>();
+
+        assert_eq!(cells(0, 0), vec![1, 5, 7, 3]);
+        assert_eq!(cells(-1, -1), vec![1, 3]);
+        assert_eq!(grid.neighbours8(0, 0).count(), 8);
+        assert_eq!(grid.neighbours8(1, 1).count(), 3);
+        assert_eq!(
+            grid.neighbours8(1, -1).map(|(p, _)| p).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 0), (0, -1)]
+        );
+    }
+
+    #[test]
+    fn test_toroidal_neighbours() {
+        let grid = numbered_grid();
+        let corner = grid
+            .neighbours_with(-1, -1, &NEIGHBOURS4, Topology::Toroidal)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            vec![((-1, 1), &6), ((0, -1), &1), ((-1, 0), &3), ((1, -1), &2)]
+        );
+        assert_eq!(grid.wrap(-5, 7), Some((1, 1)));
+        assert_eq!(Grid::<isize>::new(0, 0, 0, 0).wrap(0, 0), None);
+
+        let odd = |c: &isize| c % 2 == 1;
+        assert_eq!(
+            grid.count_neighbours(1, 1, &NEIGHBOURS8, Topology::Toroidal, odd),
+            4
+        );
+        assert_eq!(
+            grid.count_neighbours(1, 1, &NEIGHBOURS8, Topology::Bounded, odd),
+            2
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::Grid;
+use std::error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// Cells of a character map, like `#` for walls and `.` for open space.
+pub trait CharCell: Sized {
+    /// Returns `None` for characters which do not belong on the map.
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl CharCell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// A character which could not be parsed, with its 1-based position in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGridError {
+    pub line: usize,
+    pub column: usize,
+    pub character: char,
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown character {:?} at line {}, column {}",
+            self.character, self.line, self.column
+        )
+    }
+}
+
+impl error::Error for ParseGridError {}
+
+impl<T: Default + Debug> Grid<T> {
+    /// Parses one row per line, starting at `(0, 0)`. The grid is as wide as the longest
+    /// line, missing cells at the end of shorter lines are filled with `T::default()`.
+    pub fn parse_with<F>(input: &str, parse: F) -> Result<Grid<T>, ParseGridError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.iter().map(|line| line.chars().count()).max();
+        let mut grid = Grid::new(width.unwrap_or(0), lines.len(), 0, 0);
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, character) in line.chars().enumerate() {
+                let cell = parse(character).ok_or(ParseGridError {
+                    line: y + 1,
+                    column: x + 1,
+                    character,
+                })?;
+                *grid.get_mut(x as isize, y as isize).unwrap() = cell;
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+impl<T: CharCell + Debug> Grid<T> {
+    /// Renders every row through `CharCell::to_char`, the inverse of `Grid::from_str`. Each
+    /// row ends with a newline like in puzzle inputs, so text without a final newline gains
+    /// one.
+    pub fn to_text(&self) -> String {
+        self.rows()
+            .map(|row| {
+                row.iter()
+                    .map(T::to_char)
+                    .chain(Some('\n'))
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
+
+impl<T: CharCell + Default + Debug> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(input, T::from_char)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharCell, Grid, ParseGridError};
+    use std::str::FromStr;
+
+    #[derive(Debug, Default, PartialEq)]
+    enum Tile {
+        #[default]
+        Open,
+        Wall,
+    }
+
+    impl CharCell for Tile {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Tile::Open),
+                '#' => Some(Tile::Wall),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = "#####\n#...#\n#.#.#\n#####\n";
+        let grid: Grid<Tile> = input.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+        assert_eq!(grid.get(2, 2), Some(&Tile::Wall));
+        assert_eq!(grid.to_text(), input);
+
+        let chars = Grid::<char>::from_str("ab\ncd\n").unwrap();
+        assert_eq!(chars.to_text(), "ab\ncd\n");
+        assert_eq!(chars.to_string(), "ab\ncd");
+
+        // A missing final newline is added, like every puzzle input has
+        let chars = Grid::<char>::from_str("ab\ncd").unwrap();
+        assert_eq!(chars.to_text(), "ab\ncd\n");
+        assert_eq!(Grid::<char>::from_str("").unwrap().to_text(), "");
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        let grid: Grid<Tile> = "#\n###\n##".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.to_text(), "#..\n###\n##.\n");
+
+        let digits = Grid::parse_with("12\n3", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.as_slice(), &[1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_unknown_character() {
+        let error = Grid::<Tile>::from_str("###\n#.x\n###").unwrap_err();
+        assert_eq!(
+            error,
+            ParseGridError {
+                line: 2,
+                column: 3,
+                character: 'x'
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "unknown character 'x' at line 2, column 3"
+        );
+    }
+}
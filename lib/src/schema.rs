@@ -0,0 +1,193 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! A `Grid` is stored with its bounds and its cells in row-major order:
+//! `{"min_x": -1, "min_y": 0, "width": 2, "height": 1, "cells": [a, b]}`.
+//! A `SparseGrid` only stores the cells which are set, ordered by row and column:
+//! `{"cells": [{"element": a, "x": -1, "y": 0}]}`.
+//! Cells use the serialization of their own type.
+
+use crate::{Grid, GridIteratorItem, SparseGrid};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+#[derive(Serialize)]
+struct GridRef<'a, T> {
+    min_x: isize,
+    min_y: isize,
+    width: usize,
+    height: usize,
+    cells: &'a [T],
+}
+
+#[derive(Deserialize)]
+struct GridData<T> {
+    min_x: isize,
+    min_y: isize,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Debug + Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GridRef {
+            min_x: self.min_x(),
+            min_y: self.min_y(),
+            width: self.width(),
+            height: self.height(),
+            cells: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Debug + Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GridData::deserialize(deserializer)?;
+        // The sizes come from elsewhere, so the last coordinate they imply (`max_x` and `max_y`)
+        // might not fit into an isize
+        let fits = |min: isize, size: usize| {
+            isize::try_from(size)
+                .ok()
+                .and_then(|size| min.checked_add(size - 1))
+                .is_some()
+        };
+        let size = data
+            .width
+            .checked_mul(data.height)
+            .filter(|_| fits(data.min_x, data.width) && fits(data.min_y, data.height))
+            .ok_or_else(|| {
+                D::Error::custom(format!(
+                    "a {}x{} grid at ({}, {}) is out of range",
+                    data.width, data.height, data.min_x, data.min_y
+                ))
+            })?;
+        if data.cells.len() != size {
+            return Err(D::Error::custom(format!(
+                "expected {} cells for a {}x{} grid, found {}",
+                size,
+                data.width,
+                data.height,
+                data.cells.len()
+            )));
+        }
+        Ok(Grid {
+            grid: data.cells,
+            grid_size: (data.width, data.height),
+            origin: (data.min_x, data.min_y),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct SparseGridRef<'a, T> {
+    cells: Vec<GridIteratorItem<&'a T>>,
+}
+
+#[derive(Deserialize)]
+struct SparseGridData<T> {
+    cells: Vec<GridIteratorItem<T>>,
+}
+
+impl<T: Serialize> Serialize for SparseGrid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut cells = self
+            .cells()
+            .map(|((x, y), element)| GridIteratorItem { element, x, y })
+            .collect::<Vec<_>>();
+        cells.sort_by_key(|item| (item.y, item.x));
+        SparseGridRef { cells }.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SparseGrid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = SparseGridData::deserialize(deserializer)?;
+        let mut grid = SparseGrid::new();
+        for item in data.cells {
+            grid.set(item.x, item.y, item.element);
+        }
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geom::{Direction, Point2};
+    use crate::{Grid, SparseGrid};
+
+    #[test]
+    fn test_grid_json() {
+        let mut grid: Grid<u8> = Grid::new(2, 2, 1, 0);
+        grid[(0, 1)] = 7;
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"min_x":-1,"min_y":0,"width":2,"height":2,"cells":[0,0,0,7]}"#
+        );
+        assert_eq!(serde_json::from_str::<Grid<u8>>(&json).unwrap(), grid);
+
+        let error = serde_json::from_str::<Grid<u8>>(
+            r#"{"min_x":0,"min_y":0,"width":2,"height":2,"cells":[1]}"#,
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("expected 4 cells for a 2x2 grid, found 1"));
+
+        let huge = format!(
+            r#"{{"min_x":0,"min_y":0,"width":{},"height":2,"cells":[]}}"#,
+            usize::MAX
+        );
+        let error = serde_json::from_str::<Grid<u8>>(&huge).unwrap_err();
+        assert!(error.to_string().contains("grid at (0, 0) is out of range"));
+        let shifted = format!(
+            r#"{{"min_x":{},"min_y":0,"width":2,"height":0,"cells":[]}}"#,
+            isize::MAX
+        );
+        assert!(serde_json::from_str::<Grid<u8>>(&shifted).is_err());
+
+        // The last cell may sit exactly on the edge of the coordinate space
+        let edge = format!(
+            r#"{{"min_x":{},"min_y":{},"width":1,"height":2,"cells":[3,4]}}"#,
+            isize::MAX,
+            isize::MAX - 1
+        );
+        let grid = serde_json::from_str::<Grid<u8>>(&edge).unwrap();
+        assert_eq!((grid.max_x(), grid.max_y()), (isize::MAX, isize::MAX));
+        assert_eq!(grid.get(isize::MAX, isize::MAX), Some(&4));
+        let past_edge = edge.replace(r#""width":1"#, r#""width":2"#);
+        assert!(serde_json::from_str::<Grid<u8>>(&past_edge).is_err());
+        let empty_at_min = format!(
+            r#"{{"min_x":{},"min_y":0,"width":0,"height":0,"cells":[]}}"#,
+            isize::MIN
+        );
+        assert!(serde_json::from_str::<Grid<u8>>(&empty_at_min).is_err());
+    }
+
+    #[test]
+    fn test_sparse_grid_json() {
+        let mut grid = SparseGrid::new();
+        grid.set(3, -2, Direction::East);
+        grid.set(-1, 5, Direction::North);
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"cells":[{"element":"East","x":3,"y":-2},{"element":"North","x":-1,"y":5}]}"#
+        );
+
+        let reloaded: SparseGrid<Direction> = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.bounds(), grid.bounds());
+        assert_eq!(reloaded.get(-1, 5), Some(&Direction::North));
+    }
+
+    #[test]
+    fn test_geometry_json() {
+        let point = Point2::new(-4, 2);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, r#"{"x":-4,"y":2}"#);
+        assert_eq!(serde_json::from_str::<Point2<i32>>(&json).unwrap(), point);
+    }
+}
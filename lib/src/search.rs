@@ -0,0 +1,353 @@
+//! Breadth-first, Dijkstra and A* search over graphs given by a successor function.
+//!
+//! `Bfs` and `Dijkstra` are iterators visiting every reachable state once, closest first,
+//! so early exits, reachability queries and distance maps are all just iterator adaptors.
+//! States are deduplicated by themselves or, using `with_key`, by any key derived from them.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+}
+
+fn reconstruct_path<S: Clone, C>(nodes: &[Node<S, C>], index: usize) -> Vec<S> {
+    let mut path = vec![];
+    let mut next = Some(index);
+    while let Some(index) = next {
+        path.push(nodes[index].state.clone());
+        next = nodes[index].parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Visits states in order of the number of steps needed to reach them.
+pub struct Bfs<'a, S, K, N> {
+    successors: N,
+    key: Box<dyn Fn(&S) -> K + 'a>,
+    nodes: Vec<Node<S, usize>>,
+    queue: VecDeque<usize>,
+    seen: HashMap<K, usize>,
+}
+
+impl<'a, S, I, N> Bfs<'a, S, S, N>
+where
+    S: Clone + Hash + Eq + 'a,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    pub fn new(start: S, successors: N) -> Self {
+        Bfs::with_key(start, successors, S::clone)
+    }
+}
+
+impl<'a, S, K, I, N> Bfs<'a, S, K, N>
+where
+    S: Clone,
+    K: Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    /// Treats two states as the same whenever `key` maps them to the same value.
+    pub fn with_key<F: Fn(&S) -> K + 'a>(start: S, successors: N, key: F) -> Self {
+        let mut seen = HashMap::new();
+        seen.insert(key(&start), 0);
+        Bfs {
+            successors,
+            key: Box::new(key),
+            nodes: vec![Node {
+                state: start,
+                cost: 0,
+                parent: None,
+            }],
+            queue: VecDeque::from(vec![0]),
+            seen,
+        }
+    }
+
+    /// The states leading from the start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let index = *self.seen.get(&(self.key)(state))?;
+        Some(reconstruct_path(&self.nodes, index))
+    }
+}
+
+impl<'a, S, K, I, N> Iterator for Bfs<'a, S, K, N>
+where
+    S: Clone,
+    K: Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    type Item = (S, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.queue.pop_front()?;
+        let steps = self.nodes[index].cost;
+
+        for next in (self.successors)(&self.nodes[index].state) {
+            let key = (self.key)(&next);
+            if self.seen.contains_key(&key) {
+                continue;
+            }
+            self.seen.insert(key, self.nodes.len());
+            self.queue.push_back(self.nodes.len());
+            self.nodes.push(Node {
+                state: next,
+                cost: steps + 1,
+                parent: Some(index),
+            });
+        }
+
+        Some((self.nodes[index].state.clone(), steps))
+    }
+}
+
+/// Visits states in order of their total cost, or their cost plus an estimate of the
+/// remaining cost when a heuristic is given, which turns it into A*.
+pub struct Dijkstra<'a, S, C, K, N> {
+    successors: N,
+    key: Box<dyn Fn(&S) -> K + 'a>,
+    heuristic: Box<dyn Fn(&S) -> C + 'a>,
+    nodes: Vec<Node<S, C>>,
+    heap: BinaryHeap<Reverse<(C, usize)>>,
+    best: HashMap<K, C>,
+    settled: HashMap<K, usize>,
+}
+
+impl<'a, S, C, I, N> Dijkstra<'a, S, C, S, N>
+where
+    S: Clone + Hash + Eq + 'a,
+    C: Copy + Ord + Default + Add<Output = C> + 'a,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    pub fn new(start: S, successors: N) -> Self {
+        Dijkstra::with_key(start, successors, S::clone)
+    }
+}
+
+impl<'a, S, C, K, I, N> Dijkstra<'a, S, C, K, N>
+where
+    S: Clone,
+    C: Copy + Ord + Default + Add<Output = C> + 'a,
+    K: Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    /// Treats two states as the same whenever `key` maps them to the same value.
+    pub fn with_key<F: Fn(&S) -> K + 'a>(start: S, successors: N, key: F) -> Self {
+        let mut best = HashMap::new();
+        best.insert(key(&start), C::default());
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((C::default(), 0)));
+        Dijkstra {
+            successors,
+            key: Box::new(key),
+            heuristic: Box::new(|_| C::default()),
+            nodes: vec![Node {
+                state: start,
+                cost: C::default(),
+                parent: None,
+            }],
+            heap,
+            best,
+            settled: HashMap::new(),
+        }
+    }
+
+    /// Orders states by cost plus `heuristic`, which must be consistent: it never
+    /// overestimates the cost remaining to the goal, and never drops by more than the cost of
+    /// a step. Visited states are not reopened, so an inconsistent heuristic can miss the
+    /// cheapest path.
+    pub fn with_heuristic<H: Fn(&S) -> C + 'a>(mut self, heuristic: H) -> Self {
+        let start = &self.nodes[0].state;
+        self.heap = BinaryHeap::new();
+        self.heap.push(Reverse((heuristic(start), 0)));
+        self.heuristic = Box::new(heuristic);
+        self
+    }
+
+    /// The states leading from the start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let index = *self.settled.get(&(self.key)(state))?;
+        Some(reconstruct_path(&self.nodes, index))
+    }
+}
+
+impl<'a, S, C, K, I, N> Iterator for Dijkstra<'a, S, C, K, N>
+where
+    S: Clone,
+    C: Copy + Ord + Default + Add<Output = C> + 'a,
+    K: Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    type Item = (S, C);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((_, index)) = self.heap.pop()?;
+            let key = (self.key)(&self.nodes[index].state);
+            if self.settled.contains_key(&key) {
+                continue;
+            }
+            self.settled.insert(key, index);
+            let cost = self.nodes[index].cost;
+
+            for (next, step) in (self.successors)(&self.nodes[index].state) {
+                let key = (self.key)(&next);
+                let next_cost = cost + step;
+                if self.settled.contains_key(&key)
+                    || self.best.get(&key).is_some_and(|best| *best <= next_cost)
+                {
+                    continue;
+                }
+                self.best.insert(key, next_cost);
+                let priority = next_cost + (self.heuristic)(&next);
+                self.heap.push(Reverse((priority, self.nodes.len())));
+                self.nodes.push(Node {
+                    state: next,
+                    cost: next_cost,
+                    parent: Some(index),
+                });
+            }
+
+            return Some((self.nodes[index].state.clone(), cost));
+        }
+    }
+}
+
+/// The shortest path to the first state matching `goal` and its number of steps.
+pub fn bfs<S, I, N, G>(start: S, successors: N, mut goal: G) -> Option<(Vec<S>, usize)>
+where
+    S: Clone + Hash + Eq,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Bfs::new(start, successors);
+    let (state, steps) = search.find(|(state, _)| goal(state))?;
+    Some((search.path(&state)?, steps))
+}
+
+/// The cheapest path to the first state matching `goal` and its cost.
+pub fn dijkstra<S, C, I, N, G>(start: S, successors: N, goal: G) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// Like `dijkstra`, guided by a consistent `heuristic`, see `Dijkstra::with_heuristic`.
+pub fn astar<S, C, I, N, H, G>(
+    start: S,
+    successors: N,
+    heuristic: H,
+    mut goal: G,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: Fn(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Dijkstra::new(start, successors).with_heuristic(heuristic);
+    let (state, cost) = search.find(|(state, _)| goal(state))?;
+    Some((search.path(&state)?, cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{astar, bfs, dijkstra, Bfs, Dijkstra};
+    use crate::{Grid, Point};
+    use std::collections::HashMap;
+
+    fn maze() -> Grid<char> {
+        "#######\n#..#..#\n#.##.##\n#.....#\n#######"
+            .parse()
+            .unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, (x, y): Point) -> Vec<Point> {
+        grid.neighbours4(x, y)
+            .filter(|(_, c)| **c == '.')
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let (path, steps) = bfs((1, 1), |p| open_neighbours(&grid, *p), |p| *p == (5, 1)).unwrap();
+        assert_eq!(steps, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&(1, 1)));
+        assert_eq!(path.last(), Some(&(5, 1)));
+
+        assert_eq!(
+            bfs((1, 1), |p| open_neighbours(&grid, *p), |p| *p == (0, 0)),
+            None
+        );
+
+        // Distances to everything reachable
+        let distances = Bfs::new((1, 1), |p| open_neighbours(&grid, *p)).collect::<HashMap<_, _>>();
+        assert_eq!(distances.len(), 11);
+        assert_eq!(distances[&(2, 1)], 1);
+        assert_eq!(distances[&(5, 3)], 6);
+    }
+
+    #[test]
+    fn test_bfs_with_key() {
+        // Walks along a line, remembering how it got there, but only visits each position once
+        let successors = |(x, history): &(isize, Vec<isize>)| {
+            [x - 1, x + 1]
+                .iter()
+                .filter(|n| (0..5).contains(*n))
+                .map(|n| (*n, [history.clone(), vec![*x]].concat()))
+                .collect::<Vec<_>>()
+        };
+        let search = Bfs::with_key((0, vec![]), successors, |(x, _)| *x);
+        let visited = search
+            .map(|((x, history), _)| (x, history.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(visited, vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // A direct but expensive edge competes with a cheaper detour
+        let edges: HashMap<char, Vec<(char, usize)>> = vec![
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![]),
+        ]
+        .into_iter()
+        .collect();
+        let successors = |node: &char| edges[node].clone();
+
+        let (path, cost) = dijkstra('a', successors, |n| *n == 'd').unwrap();
+        assert_eq!((path, cost), (vec!['a', 'b', 'c', 'd'], 6));
+
+        let order = Dijkstra::new('a', successors).collect::<Vec<_>>();
+        assert_eq!(order, vec![('a', 0), ('b', 1), ('c', 3), ('d', 6)]);
+
+        let grid = maze();
+        let manhattan = |(x, y): &Point| ((5 - x).abs() + (1 - y).abs()) as usize;
+        let neighbours = |p: &Point| open_neighbours(&grid, *p).into_iter().map(|n| (n, 1));
+        let (path, cost) = astar((1, 1), neighbours, manhattan, |p| *p == (5, 1)).unwrap();
+        assert_eq!((path.len(), cost), (9, 8));
+    }
+}
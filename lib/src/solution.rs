@@ -0,0 +1,204 @@
+//! A common shape for the puzzle of every day, so that a single runner can solve all of them.
+
+use crate::{input, log};
+use std::fmt::{Display, Error, Formatter};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+/// Parses the part number, `1` or `2`.
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("Invalid part '{}'", other)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+pub trait Solution {
+    /// The puzzle input after parsing, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parses the puzzle input, describing what is wrong with it when it is malformed.
+    fn parse(&self, input: &str) -> Result<Self::Input, String>;
+
+    fn part1(&self, input: &Self::Input) -> Self::Answer1;
+
+    fn part2(&self, input: &Self::Input) -> Self::Answer2;
+}
+
+/// The answer to a part which has not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "unsolved")
+    }
+}
+
+/// How long parsing and each solved part took.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+impl Timings {
+    pub fn part(&self, part: Part) -> Option<Duration> {
+        self.parts
+            .iter()
+            .find(|(solved, _)| *solved == part)
+            .map(|(_, duration)| *duration)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .map(|(_, duration)| *duration)
+                .sum::<Duration>()
+    }
+}
+
+/// A `Solution` with its types erased, so that days with different inputs fit into one list.
+pub trait Puzzle {
+    /// Parses `input` once and solves each of `parts`, returning the formatted answers together
+    /// with how long each step took, or why `input` could not be parsed.
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<(Vec<String>, Timings), String>;
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<String>, String> {
+        self.solve_timed(input, parts).map(|(answers, _)| answers)
+    }
+}
+
+impl<S: Solution> Puzzle for S {
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<(Vec<String>, Timings), String> {
+        let start = Instant::now();
+        let input = self
+            .parse(input)
+            .map_err(|error| format!("Invalid input: {}", error))?;
+        let mut timings = Timings {
+            parse: start.elapsed(),
+            parts: Vec::new(),
+        };
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part1(&input).to_string(),
+                    Part::Two => self.part2(&input).to_string(),
+                };
+                timings.parts.push((part, start.elapsed()));
+                answer
+            })
+            .collect();
+        Ok((answers, timings))
+    }
+}
+
+/// Solves and prints both parts, which is all the binary of a single day does.
+pub fn run<P: Puzzle + ?Sized>(puzzle: &P, input: &str) -> Result<(), String> {
+    for (part, answer) in Part::BOTH.iter().zip(puzzle.solve(input, &Part::BOTH)?) {
+        println!("{}: {}", part, answer);
+    }
+    Ok(())
+}
+
+/// Reads the input of `day` as `input::from_args` does and solves it, exiting when it cannot
+/// be read or parsed. Diagnostics are filtered by `AOC_LOG`.
+pub fn run_day<P: Puzzle + ?Sized>(day: u8, puzzle: &P) {
+    if let Err(error) = log::init_from_env() {
+        eprintln!("{}", error);
+        process::exit(2);
+    }
+    let solved = input::from_args(day)
+        .map_err(|error| error.to_string())
+        .and_then(|input| run(puzzle, &input));
+    if let Err(error) = solved {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::{Part, Puzzle, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = String;
+
+        fn parse(&self, input: &str) -> Result<Vec<i32>, String> {
+            input
+                .lines()
+                .map(|line| line.parse().map_err(|_| format!("'{}' is no number", line)))
+                .collect()
+        }
+
+        fn part1(&self, input: &Vec<i32>) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(&self, input: &Vec<i32>) -> String {
+            format!("{} numbers", input.len())
+        }
+    }
+
+    #[test]
+    fn test_solve_parts() {
+        let puzzles: Vec<&dyn Puzzle> = vec![&Sum];
+        assert_eq!(
+            puzzles[0].solve("1\n2\n3", &Part::BOTH),
+            Ok(vec![String::from("6"), String::from("3 numbers")])
+        );
+        assert_eq!(
+            puzzles[0].solve("4", &[Part::Two]),
+            Ok(vec![String::from("1 numbers")])
+        );
+        assert_eq!(
+            puzzles[0].solve("1\nx", &Part::BOTH),
+            Err(String::from("Invalid input: 'x' is no number"))
+        );
+        assert_eq!(Part::Two.to_string(), "Part 2");
+        assert_eq!("1".parse(), Ok(Part::One));
+
+        let (answers, timings) = puzzles[0].solve_timed("1\n2", &[Part::Two]).unwrap();
+        assert_eq!(answers, vec!["2 numbers"]);
+        assert!(timings.part(Part::Two).is_some());
+        assert_eq!(timings.part(Part::One), None);
+        assert!(timings.total() >= timings.parse);
+        assert_eq!("3".parse::<Part>(), Err(String::from("Invalid part '3'")));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Grid, GridIteratorItem};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Error, Formatter};
+use std::ops::RangeInclusive;
+
+/// An unbounded grid which only stores the cells that were set, growing its bounding box
+/// as needed. Useful whenever the size of a map is not known up front.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+    bounds: Option<((isize, isize), (isize, isize))>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T: Display + Default> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut output = String::from("");
+        let empty = T::default();
+
+        for item in self.iter() {
+            if item.x == self.min_x().unwrap() && item.y != self.min_y().unwrap() {
+                output.push('\n');
+            }
+            output.push_str(format!("{}", item.element.unwrap_or(&empty)).as_str());
+        }
+
+        write!(f, "{}", output)
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    /// Sets a cell, returning its previous value. Never fails, the bounds grow to fit.
+    pub fn set(&mut self, x: isize, y: isize, item: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => ((x, y), (x, y)),
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            }
+        });
+        self.cells.insert((x, y), item)
+    }
+
+    /// Removes a cell, shrinking the bounds if it was on the edge.
+    pub fn remove(&mut self, x: isize, y: isize) -> Option<T> {
+        let item = self.cells.remove(&(x, y))?;
+        self.bounds = self
+            .cells
+            .keys()
+            .fold(None, |bounds, &(x, y)| match bounds {
+                None => Some(((x, y), (x, y))),
+                Some(((min_x, min_y), (max_x, max_y))) => {
+                    Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))))
+                }
+            });
+        Some(item)
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.cells.contains_key(&(x, y))
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn min_x(&self) -> Option<isize> {
+        self.bounds.map(|((min_x, _), _)| min_x)
+    }
+
+    pub fn max_x(&self) -> Option<isize> {
+        self.bounds.map(|(_, (max_x, _))| max_x)
+    }
+
+    pub fn min_y(&self) -> Option<isize> {
+        self.bounds.map(|((_, min_y), _)| min_y)
+    }
+
+    pub fn max_y(&self) -> Option<isize> {
+        self.bounds.map(|(_, (_, max_y))| max_y)
+    }
+
+    /// The smallest ranges covering every cell set so far, `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<(RangeInclusive<isize>, RangeInclusive<isize>)> {
+        self.bounds
+            .map(|((min_x, min_y), (max_x, max_y))| (min_x..=max_x, min_y..=max_y))
+    }
+
+    /// Visits every position within the bounds in row-major order, including unset ones.
+    pub fn iter(&self) -> SparseGridIterator<'_, T> {
+        let (x, y) = self.bounds.map_or((0, 0), |(min, _)| min);
+        SparseGridIterator { grid: self, x, y }
+    }
+
+    /// Visits only the cells which were set, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = ((isize, isize), &T)> {
+        self.cells.iter().map(|(&position, item)| (position, item))
+    }
+}
+
+impl<T: Debug + Default + Clone> SparseGrid<T> {
+    /// Copies the occupied bounds into a dense `Grid`, unset cells become `T::default()`.
+    pub fn to_grid(&self) -> Grid<T> {
+        let mut grid = match self.bounds() {
+            Some((x_range, y_range)) => Grid::with_bounds(x_range, y_range),
+            None => Grid::new(0, 0, 0, 0),
+        };
+        for (&(x, y), item) in &self.cells {
+            grid.set(x, y, item.clone()).unwrap();
+        }
+        grid
+    }
+}
+
+pub struct SparseGridIterator<'a, T> {
+    grid: &'a SparseGrid<T>,
+    x: isize,
+    y: isize,
+}
+
+impl<'a, T> Iterator for SparseGridIterator<'a, T> {
+    type Item = GridIteratorItem<Option<&'a T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((min_x, _), (max_x, max_y)) = self.grid.bounds?;
+        if self.x > max_x {
+            self.x = min_x;
+            self.y += 1;
+        }
+        if self.y > max_y {
+            return None;
+        }
+
+        let item = GridIteratorItem {
+            element: self.grid.get(self.x, self.y),
+            x: self.x,
+            y: self.y,
+        };
+        self.x += 1;
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SparseGrid;
+
+    #[test]
+    fn test_sparse_grid_grows() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.iter().count(), 0);
+
+        grid.set(0, 0, 'a');
+        grid.set(-1000, 3, 'b');
+        grid.set(2, -7, 'c');
+        assert_eq!(grid.bounds(), Some((-1000..=2, -7..=3)));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(-1000, 3), Some(&'b'));
+        assert_eq!(grid.get(-999, 3), None);
+
+        assert_eq!(grid.set(0, 0, 'd'), Some('a'));
+        assert_eq!(grid.remove(-1000, 3), Some('b'));
+        assert_eq!(grid.bounds(), Some((0..=2, -7..=0)));
+    }
+
+    #[test]
+    fn test_sparse_grid_iteration_and_display() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        grid.set(-1, -1, '#');
+        grid.set(1, 0, '#');
+
+        let positions = grid
+            .iter()
+            .map(|item| (item.x, item.y, item.element.is_some()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                (-1, -1, true),
+                (0, -1, false),
+                (1, -1, false),
+                (-1, 0, false),
+                (0, 0, false),
+                (1, 0, true),
+            ]
+        );
+
+        // Unset cells are rendered as the default character
+        let rendered = format!("{}", grid).replace('\0', ".");
+        assert_eq!(rendered, "#..\n..#");
+        assert_eq!(format!("{}", grid.to_grid()), format!("{}", grid));
+    }
+}
@@ -0,0 +1,217 @@
+//! Animates grids in a terminal using ANSI escape codes.
+//!
+//! Every frame is compared to the previous one and only the cells which changed are redrawn,
+//! which keeps large, mostly static screens like an arcade cabinet smooth.
+
+use crate::export::Rgb;
+use crate::Grid;
+use std::fmt::Debug;
+use std::io::{self, Stdout, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const CLEAR_SCREEN: &str = "\x1B[2J\x1B[H";
+const CLEAR_LINE: &str = "\x1B[2K";
+const RESET: &str = "\x1B[0m";
+
+/// How a single cell is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub character: char,
+    pub colour: Option<Rgb>,
+}
+
+impl Glyph {
+    pub fn new(character: char) -> Self {
+        Glyph {
+            character,
+            colour: None,
+        }
+    }
+
+    pub fn coloured(character: char, colour: Rgb) -> Self {
+        Glyph {
+            character,
+            colour: Some(colour),
+        }
+    }
+
+    fn write<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        match self.colour {
+            Some([r, g, b]) => write!(
+                out,
+                "\x1B[38;2;{};{};{}m{}{}",
+                r, g, b, self.character, RESET
+            ),
+            None => write!(out, "{}", self.character),
+        }
+    }
+}
+
+struct Frame {
+    width: usize,
+    height: usize,
+    glyphs: Vec<Glyph>,
+    status: String,
+}
+
+/// Draws successive frames of a grid with a status line below it.
+pub struct Renderer<W: Write> {
+    out: W,
+    previous: Option<Frame>,
+    frame_interval: Option<Duration>,
+    last_draw: Option<Instant>,
+}
+
+impl Renderer<Stdout> {
+    pub fn stdout() -> Self {
+        Renderer::new(io::stdout())
+    }
+}
+
+impl<W: Write> Renderer<W> {
+    pub fn new(out: W) -> Self {
+        Renderer {
+            out,
+            previous: None,
+            frame_interval: None,
+            last_draw: None,
+        }
+    }
+
+    /// Waits between frames so that no more than `fps` frames are drawn per second.
+    pub fn with_frame_rate(mut self, fps: u32) -> Self {
+        self.frame_interval = Some(Duration::from_secs(1) / fps.max(1));
+        self
+    }
+
+    /// Draws `grid` with the glyphs picked by `glyph` and `status` on the line below. Only
+    /// the first frame, or one of a different size, redraws the whole screen.
+    pub fn draw<T, F>(&mut self, grid: &Grid<T>, glyph: F, status: &str) -> io::Result<()>
+    where
+        T: Debug,
+        F: Fn(&T) -> Glyph,
+    {
+        if let (Some(interval), Some(last_draw)) = (self.frame_interval, self.last_draw) {
+            if let Some(remaining) = interval.checked_sub(last_draw.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+        self.last_draw = Some(Instant::now());
+
+        let frame = Frame {
+            width: grid.width(),
+            height: grid.height(),
+            glyphs: grid.as_slice().iter().map(glyph).collect(),
+            status: status.to_string(),
+        };
+
+        match self.previous.take() {
+            Some(previous) if (previous.width, previous.height) == (frame.width, frame.height) => {
+                self.draw_changes(&previous, &frame)?
+            }
+            _ => self.draw_all(&frame)?,
+        }
+        self.previous = Some(frame);
+        self.out.flush()
+    }
+
+    /// Moves the cursor below the last frame, so that further output does not overwrite it.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(frame) = &self.previous {
+            write!(self.out, "\x1B[{};1H", frame.height + 2)?;
+        }
+        self.out.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn draw_all(&mut self, frame: &Frame) -> io::Result<()> {
+        write!(self.out, "{}", CLEAR_SCREEN)?;
+        for row in frame.glyphs.chunks(frame.width.max(1)) {
+            for glyph in row {
+                glyph.write(&mut self.out)?;
+            }
+            writeln!(self.out)?;
+        }
+        write!(self.out, "{}", frame.status)
+    }
+
+    fn draw_changes(&mut self, previous: &Frame, frame: &Frame) -> io::Result<()> {
+        let changes = previous.glyphs.iter().zip(&frame.glyphs).enumerate();
+        for (index, (old, new)) in changes {
+            if old != new {
+                // ANSI positions are 1-based rows and columns
+                let (row, column) = (index / frame.width + 1, index % frame.width + 1);
+                write!(self.out, "\x1B[{};{}H", row, column)?;
+                new.write(&mut self.out)?;
+            }
+        }
+        if previous.status != frame.status {
+            write!(
+                self.out,
+                "\x1B[{};1H{}{}",
+                frame.height + 1,
+                CLEAR_LINE,
+                frame.status
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::term::{Glyph, Renderer};
+    use crate::Grid;
+    use std::time::{Duration, Instant};
+
+    fn render(frames: &[&str], status: &[&str]) -> String {
+        let mut renderer = Renderer::new(vec![]);
+        for (frame, status) in frames.iter().zip(status) {
+            let grid: Grid<char> = frame.parse().unwrap();
+            renderer.draw(&grid, |c| Glyph::new(*c), status).unwrap();
+        }
+        String::from_utf8(renderer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_redraws_only_changes() {
+        let output = render(
+            &["ab\ncd", "ab\nxd", "ab\nxd"],
+            &["Score: 1", "Score: 1", "Score: 2"],
+        );
+        assert_eq!(
+            output,
+            "\x1B[2J\x1B[Hab\ncd\nScore: 1\x1B[2;1Hx\x1B[3;1H\x1B[2KScore: 2"
+        );
+    }
+
+    #[test]
+    fn test_resize_redraws_everything() {
+        let output = render(&["a", "ab"], &["", ""]);
+        assert_eq!(output, "\x1B[2J\x1B[Ha\n\x1B[2J\x1B[Hab\n");
+    }
+
+    #[test]
+    fn test_colours_and_frame_rate() {
+        let grid: Grid<bool> = Grid::new(2, 1, 0, 0);
+        let mut renderer = Renderer::new(vec![]).with_frame_rate(50);
+        let glyph = |on: &bool| match on {
+            true => Glyph::new('#'),
+            false => Glyph::coloured('.', [255, 0, 0]),
+        };
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            renderer.draw(&grid, glyph, "").unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(40));
+
+        let output = String::from_utf8(renderer.into_inner()).unwrap();
+        let red_dot = "\x1B[38;2;255;0;0m.\x1B[0m";
+        assert_eq!(output, format!("\x1B[2J\x1B[H{}{}\n", red_dot, red_dot));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{Grid, Point};
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// An axis-aligned rectangle of cells from `min` to `max`, both inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub fn new(min: Point, max: Point) -> Self {
+        Rect { min, max }
+    }
+
+    /// The `width` x `height` rectangle with its top left corner at `at`.
+    pub fn with_size(at: Point, width: usize, height: usize) -> Self {
+        let max = (at.0 + width as isize - 1, at.1 + height as isize - 1);
+        Rect { min: at, max }
+    }
+
+    pub fn x_range(&self) -> RangeInclusive<isize> {
+        self.min.0..=self.max.0
+    }
+
+    pub fn y_range(&self) -> RangeInclusive<isize> {
+        self.min.1..=self.max.1
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        self.x_range().contains(&x) && self.y_range().contains(&y)
+    }
+
+    /// The cells covered by both rectangles, `None` if they do not overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = (self.min.0.max(other.min.0), self.min.1.max(other.min.1));
+        let max = (self.max.0.min(other.max.0), self.max.1.min(other.max.1));
+        if min.0 > max.0 || min.1 > max.1 {
+            return None;
+        }
+        Some(Rect { min, max })
+    }
+}
+
+/// A borrowed rectangular part of a `Grid`, addressed with the coordinates of the grid.
+pub struct SubGrid<'a, T: Debug> {
+    grid: &'a Grid<T>,
+    rect: Option<Rect>,
+}
+
+impl<'a, T: Debug> SubGrid<'a, T> {
+    /// The part of the view which lies within the grid, `None` if nothing does.
+    pub fn rect(&self) -> Option<Rect> {
+        self.rect
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&'a T> {
+        if !self.rect?.contains((x, y)) {
+            return None;
+        }
+        self.grid.get(x, y)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> {
+        let grid = self.grid;
+        self.rect
+            .into_iter()
+            .flat_map(move |rect| grid.cells_in_rect(rect.x_range(), rect.y_range()))
+    }
+}
+
+impl<T: Debug + Clone> SubGrid<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        match self.rect {
+            Some(rect) => {
+                let cells = self.iter().map(|(_, cell)| cell.clone()).collect();
+                Grid::from_cells(cells, rect)
+            }
+            None => Grid::from_cells(vec![], Rect::new((0, 0), (-1, -1))),
+        }
+    }
+}
+
+impl<T: Debug> Grid<T> {
+    /// The rectangle covered by the grid.
+    pub fn rect(&self) -> Rect {
+        Rect::new((self.min_x(), self.min_y()), (self.max_x(), self.max_y()))
+    }
+
+    /// A view of the cells within `rect`, clipped to the bounds of the grid.
+    pub fn subgrid(&self, rect: Rect) -> SubGrid<'_, T> {
+        SubGrid {
+            grid: self,
+            rect: self.rect().intersection(&rect),
+        }
+    }
+
+    fn from_cells(grid: Vec<T>, rect: Rect) -> Grid<T> {
+        let width = (rect.max.0 - rect.min.0 + 1).max(0) as usize;
+        let height = (rect.max.1 - rect.min.1 + 1).max(0) as usize;
+        assert_eq!(grid.len(), width * height);
+        Grid {
+            grid,
+            grid_size: (width, height),
+            origin: rect.min,
+        }
+    }
+}
+
+impl<T: Debug + Clone> Grid<T> {
+    /// Builds a `width` x `height` grid at the same origin, where `source` maps each position
+    /// relative to the new top left corner to one relative to the old top left corner.
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Grid<T>
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (old_x, old_y) = source(x, y);
+                cells.push(self.grid[old_y * self.width() + old_x].clone());
+            }
+        }
+        Grid::from_cells(cells, Rect::with_size(self.origin, width, height))
+    }
+
+    /// Rotates by 90 degrees clockwise, the top row becomes the rightmost column.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(height, width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotates by 90 degrees counter-clockwise, the top row becomes the leftmost column.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(height, width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors left and right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(width, height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors top and bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(width, height, |x, y| (x, height - 1 - y))
+    }
+
+    /// Mirrors along the diagonal from the top left corner, rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        self.remap(height, width, |x, y| (y, x))
+    }
+
+    /// Copies the cells within `rect` into a new grid, keeping their coordinates.
+    pub fn crop(&self, rect: Rect) -> Grid<T> {
+        self.subgrid(rect).to_grid()
+    }
+
+    /// Copies all of `other` into this grid, with the top left corner of `other` at `at`.
+    /// Cells which would end up outside of this grid are skipped.
+    pub fn paste(&mut self, other: &Grid<T>, at: Point) {
+        let (dx, dy) = (at.0 - other.min_x(), at.1 - other.min_y());
+        for ((x, y), cell) in other.iter() {
+            if let Some(target) = self.get_mut(x + dx, y + dy) {
+                *target = cell.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Rect};
+
+    fn sample() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_rotations_and_flips() {
+        let grid = sample();
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+
+        let rotated = (0..4).fold(grid.clone(), |g, _| g.rotate_cw());
+        assert_eq!(rotated.to_string(), grid.to_string());
+        assert_eq!(grid.rotate_cw().rotate_ccw().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_crop_and_subgrid() {
+        let mut grid: Grid<char> = Grid::new(4, 4, 2, 2);
+        for ((x, y), cell) in grid.iter_mut() {
+            *cell = (b'a' + ((x + 2) + 4 * (y + 2)) as u8) as char;
+        }
+
+        let view = grid.subgrid(Rect::new((-1, -1), (5, 0)));
+        assert_eq!(view.rect(), Some(Rect::new((-1, -1), (1, 0))));
+        assert_eq!(view.get(-1, -1), Some(&'f'));
+        assert_eq!(view.get(-2, -1), None);
+        assert_eq!(view.iter().count(), 6);
+
+        let cropped = grid.crop(Rect::with_size((-1, -1), 2, 2));
+        assert_eq!(cropped.to_string(), "fg\njk");
+        assert_eq!((cropped.min_x(), cropped.min_y()), (-1, -1));
+        assert_eq!(cropped.get(0, 0), Some(&'k'));
+
+        assert_eq!(grid.subgrid(Rect::new((10, 10), (12, 12))).rect(), None);
+        assert_eq!(grid.crop(Rect::new((10, 10), (12, 12))).width(), 0);
+    }
+
+    #[test]
+    fn test_paste() {
+        let mut grid: Grid<char> = "....\n....\n....".parse().unwrap();
+        grid.paste(&sample(), (2, 1));
+        assert_eq!(grid.to_string(), "....\n..ab\n..de");
+
+        grid.paste(&sample().rotate_cw(), (-1, 0));
+        assert_eq!(grid.to_string(), "a...\nb.ab\nc.de");
+    }
+}